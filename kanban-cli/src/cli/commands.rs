@@ -2,9 +2,16 @@
 
 use clap::{Parser, Subcommand};
 
+use crate::config::Config;
 use crate::db::Database;
-use crate::models::{CreateBlockerRequest, CreateFeatureRequest, TaskBuilder};
-use crate::operations::{blockers, features, metrics, tasks, OperationError};
+use crate::models::{
+    BoardBackup, CreateBlockerRequest, CreateFeatureRequest, TaskBuilder, TaskTemplate,
+    UpdateTaskRequest,
+};
+use crate::operations::{
+    backup, blockers, checklists, export, features, iterations, labels, metrics, plans, tasks,
+    templates, time, wip, OperationError,
+};
 use crate::state_machine::{BlockerType, FeatureStatus, TaskStatus};
 
 use super::output::*;
@@ -19,6 +26,10 @@ pub struct Cli {
     #[arg(long, default_value = "kanban/tasks.db")]
     pub db: String,
 
+    /// Path to the config file (optional)
+    #[arg(long, default_value = "kanban/config.json")]
+    pub config: String,
+
     /// Output format
     #[arg(long, default_value = "table")]
     pub format: String,
@@ -49,6 +60,40 @@ pub enum Commands {
         #[command(subcommand)]
         command: AgentCommands,
     },
+    /// Label management commands
+    Label {
+        #[command(subcommand)]
+        command: LabelCommands,
+    },
+    /// Task template commands
+    Template {
+        #[command(subcommand)]
+        command: TemplateCommands,
+    },
+    /// Sprint (iteration) planning commands
+    Sprint {
+        #[command(subcommand)]
+        command: SprintCommands,
+    },
+    /// Work-in-progress limit commands
+    ///
+    /// Limits apply to `task move` and `task assign`; pass --override-wip to
+    /// go past one. Automatic moves when a blocker is added or resolved skip
+    /// the limits.
+    Wip {
+        #[command(subcommand)]
+        command: WipCommands,
+    },
+    /// Export the board for sharing
+    Export {
+        #[command(subcommand)]
+        command: ExportCommands,
+    },
+    /// Import a board backup
+    Import {
+        #[command(subcommand)]
+        command: ImportCommands,
+    },
     /// Launch interactive TUI
     Tui,
     /// Alias for tui
@@ -70,20 +115,26 @@ pub enum TaskCommands {
         /// Filter by assigned agent
         #[arg(long)]
         agent: Option<String>,
+        /// Filter by label (repeatable; tasks must have every label)
+        #[arg(long = "label")]
+        labels: Vec<String>,
         /// Output as JSON
         #[arg(long)]
         json: bool,
     },
     /// Create a new task
     Create {
-        /// Task title
-        title: String,
+        /// Task title (optional with --template unless its pattern uses {title})
+        title: Option<String>,
         /// Feature ID
         #[arg(long)]
         feature: String,
-        /// Priority (lower is higher priority)
-        #[arg(long, default_value = "100")]
-        priority: i32,
+        /// Template to start from; other flags override its values
+        #[arg(long)]
+        template: Option<String>,
+        /// Priority (lower is higher priority, default 100)
+        #[arg(long)]
+        priority: Option<i32>,
         /// Estimated hours
         #[arg(long)]
         estimate: Option<f64>,
@@ -93,6 +144,9 @@ pub enum TaskCommands {
         /// Task this depends on
         #[arg(long)]
         depends_on: Option<String>,
+        /// Label to attach (repeatable)
+        #[arg(long = "label")]
+        labels: Vec<String>,
     },
     /// Show task details
     Show {
@@ -108,6 +162,9 @@ pub enum TaskCommands {
         task_id: String,
         /// New status
         status: String,
+        /// Move even if it exceeds a WIP limit
+        #[arg(long)]
+        override_wip: bool,
     },
     /// Assign task to agent
     Assign {
@@ -115,6 +172,9 @@ pub enum TaskCommands {
         task_id: String,
         /// Agent ID
         agent_id: String,
+        /// Assign even if it exceeds the agent's WIP limit
+        #[arg(long)]
+        override_wip: bool,
     },
     /// Update task fields
     Update {
@@ -123,12 +183,119 @@ pub enum TaskCommands {
         /// New priority
         #[arg(long)]
         priority: Option<i32>,
+        /// New title
+        #[arg(long)]
+        title: Option<String>,
+        /// New description (empty string clears it)
+        #[arg(long)]
+        description: Option<String>,
+        /// New estimated hours
+        #[arg(long)]
+        estimate: Option<f64>,
+        /// Remove the estimate
+        #[arg(long, conflicts_with = "estimate")]
+        clear_estimate: bool,
+        /// Move the task to another feature
+        #[arg(long)]
+        feature: Option<String>,
     },
     /// Show task history
     History {
         /// Task ID
         task_id: String,
     },
+    /// Permanently delete a task
+    Delete {
+        /// Task ID
+        task_id: String,
+        /// Also delete the task's history, comments, time, dependencies, blockers,
+        /// checklist, and sprint commitments
+        #[arg(long)]
+        force: bool,
+    },
+    /// Hide a task from boards and lists, keeping its history
+    Archive {
+        /// Task ID
+        task_id: String,
+    },
+    /// Log hours spent on a task
+    Log {
+        /// Task ID
+        task_id: String,
+        /// Hours spent
+        #[arg(long)]
+        hours: f64,
+    },
+    /// Start or stop a work timer on a task
+    Timer {
+        #[command(subcommand)]
+        command: TimerCommands,
+    },
+    /// Manage a task's checklist
+    Check {
+        #[command(subcommand)]
+        command: CheckCommands,
+    },
+    /// Create tasks in bulk from a JSON, YAML, or CSV plan file
+    Import {
+        /// Plan file (.json, .yaml, or .csv)
+        file: String,
+        /// Feature for entries that do not name one
+        #[arg(long)]
+        feature: Option<String>,
+    },
+    /// Export a feature's tasks as a plan file that `task import` accepts
+    Export {
+        /// Feature ID
+        #[arg(long)]
+        feature: String,
+        /// Plan format (json, yaml, or csv); defaults to the output file's extension
+        #[arg(long)]
+        format: Option<String>,
+        /// Write to a file instead of stdout
+        #[arg(long)]
+        output: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum CheckCommands {
+    /// Add an item to the checklist
+    Add {
+        /// Task ID
+        task_id: String,
+        /// Item text
+        text: String,
+    },
+    /// Mark an item done, or not done if it already is
+    Toggle {
+        /// Task ID
+        task_id: String,
+        /// Item number as shown by `check list`
+        item: i64,
+    },
+    /// List checklist items
+    List {
+        /// Task ID
+        task_id: String,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TimerCommands {
+    /// Start a timer
+    Start {
+        /// Task ID
+        task_id: String,
+    },
+    /// Stop the running timer and log the elapsed time
+    Stop {
+        /// Task ID
+        task_id: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -220,6 +387,196 @@ pub enum BlockerCommands {
     },
 }
 
+#[derive(Subcommand)]
+pub enum LabelCommands {
+    /// List labels with their task counts
+    List {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Attach labels to a task
+    Add {
+        /// Task ID
+        task_id: String,
+        /// Labels to attach
+        #[arg(required = true)]
+        labels: Vec<String>,
+    },
+    /// Remove a label from a task
+    Remove {
+        /// Task ID
+        task_id: String,
+        /// Label to remove
+        label: String,
+    },
+    /// Show progress and effort per label
+    Metrics {
+        /// Limit to one feature
+        #[arg(long)]
+        feature: Option<String>,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TemplateCommands {
+    /// List templates
+    List {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create a template
+    Create {
+        /// Template name (e.g. code-review)
+        name: String,
+        /// Title pattern; may use {title}, {feature}, and {feature_id}
+        #[arg(long)]
+        title: String,
+        /// Description skeleton; may use {feature} and {feature_id}
+        #[arg(long)]
+        description: Option<String>,
+        /// Estimated hours
+        #[arg(long)]
+        estimate: Option<f64>,
+        /// Priority (lower is higher priority)
+        #[arg(long)]
+        priority: Option<i32>,
+        /// Label to attach (repeatable)
+        #[arg(long = "label")]
+        labels: Vec<String>,
+        /// Template whose tasks the new task depends on (repeatable)
+        #[arg(long)]
+        depends_on: Vec<String>,
+    },
+    /// Delete a template
+    Delete {
+        /// Template name
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum SprintCommands {
+    /// List sprints with committed and completed work
+    List {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create a sprint
+    Create {
+        /// Sprint name (its ID is a slug of the name)
+        name: String,
+        /// First day (YYYY-MM-DD)
+        #[arg(long)]
+        start: String,
+        /// Last day, inclusive (YYYY-MM-DD)
+        #[arg(long)]
+        end: String,
+    },
+    /// Commit tasks to a sprint
+    Add {
+        /// Sprint ID
+        sprint_id: String,
+        /// Task IDs
+        #[arg(required = true)]
+        task_ids: Vec<String>,
+        /// Story points for each task
+        #[arg(long)]
+        points: Option<i64>,
+    },
+    /// Take a task out of a sprint
+    Remove {
+        /// Sprint ID
+        sprint_id: String,
+        /// Task ID
+        task_id: String,
+    },
+    /// Committed vs completed points and hours, with rolling velocity
+    Report {
+        /// Sprint ID (default: the sprint running today)
+        sprint_id: Option<String>,
+        /// Number of finished sprints to average for velocity
+        #[arg(long, default_value = "3")]
+        window: usize,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Delete a sprint; its tasks stay on the board
+    Delete {
+        /// Sprint ID
+        sprint_id: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum WipCommands {
+    /// List WIP limits
+    List {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Limit the tasks in a status, per feature column or for one agent
+    Set {
+        /// Status (in-progress, blocked, in-qa)
+        status: String,
+        /// Maximum number of tasks
+        max_tasks: i64,
+        /// Limit this agent's tasks instead of the column
+        #[arg(long)]
+        agent: Option<String>,
+    },
+    /// Remove a WIP limit
+    Clear {
+        /// Status
+        status: String,
+        /// Clear the agent's limit instead of the column's
+        #[arg(long)]
+        agent: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ExportCommands {
+    /// Markdown status report with task tables, blockers, and metrics
+    Markdown {
+        /// Limit to one feature (default: all active features)
+        #[arg(long)]
+        feature: Option<String>,
+        /// Group tasks by status or feature
+        #[arg(long, default_value = "status")]
+        group_by: String,
+        /// Write to a file instead of stdout
+        #[arg(long)]
+        output: Option<String>,
+    },
+    /// Versioned JSON backup of the whole board, for `kanban import json`
+    Json {
+        /// Write to a file instead of stdout
+        #[arg(long)]
+        output: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ImportCommands {
+    /// Restore a backup written by `kanban export json`
+    Json {
+        /// Backup file
+        file: String,
+        /// Remove the existing board first instead of requiring an empty one
+        /// (refused while workflow run records exist)
+        #[arg(long)]
+        replace: bool,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum AgentCommands {
     /// List all agents
@@ -253,7 +610,7 @@ impl Cli {
         }
 
         let db = Database::open(&self.db)?;
-        let json = self.format == "json";
+        let json = matches!(OutputFormat::from_name(&self.format), OutputFormat::Json);
 
         match &self.command {
             Commands::Init => {
@@ -263,12 +620,20 @@ impl Cli {
             Commands::Tui | Commands::Board => {
                 // TUI will be implemented separately
                 println!("Launching TUI...");
-                crate::tui::run(&db)
+                let config = Config::load(&self.config)
+                    .map_err(|e| OperationError::Validation(e.to_string()))?;
+                crate::tui::run(&db, &config)
             }
             Commands::Task { command } => self.handle_task_command(&db, command, json),
             Commands::Feature { command } => self.handle_feature_command(&db, command, json),
             Commands::Blocker { command } => self.handle_blocker_command(&db, command, json),
             Commands::Agent { command } => self.handle_agent_command(&db, command, json),
+            Commands::Label { command } => self.handle_label_command(&db, command, json),
+            Commands::Template { command } => self.handle_template_command(&db, command, json),
+            Commands::Sprint { command } => self.handle_sprint_command(&db, command),
+            Commands::Wip { command } => self.handle_wip_command(&db, command),
+            Commands::Export { command } => self.handle_export_command(&db, command),
+            Commands::Import { command } => self.handle_import_command(&db, command),
         }
     }
 
@@ -283,10 +648,21 @@ impl Cli {
                 feature,
                 status,
                 agent,
+                labels: label_filter,
                 json,
             } => {
                 let status = status.as_ref().and_then(|s| s.parse().ok());
-                let task_list = tasks::list_tasks(db, feature.as_deref(), status, agent.as_deref())?;
+                let label_filter = label_filter
+                    .iter()
+                    .map(|l| labels::normalize_label(l))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let task_list = tasks::list_tasks(
+                    db,
+                    feature.as_deref(),
+                    status,
+                    agent.as_deref(),
+                    &label_filter,
+                )?;
 
                 if *json {
                     println!("{}", serde_json::to_string_pretty(&task_list).unwrap());
@@ -297,16 +673,38 @@ impl Cli {
             TaskCommands::Create {
                 title,
                 feature,
+                template,
                 priority,
                 estimate,
                 description,
                 depends_on,
+                labels: new_labels,
             } => {
-                let mut builder = TaskBuilder::new()
-                    .feature_id(feature)
-                    .title(title)
-                    .priority(*priority);
+                // Validate labels before creating anything
+                let new_labels = new_labels
+                    .iter()
+                    .map(|l| labels::normalize_label(l))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let template = template
+                    .as_deref()
+                    .map(|name| templates::get_template(db, name))
+                    .transpose()?;
+                let mut builder = match (&template, title) {
+                    (Some(template), title) => {
+                        templates::template_builder(db, template, feature, title.as_deref())?
+                    }
+                    (None, Some(title)) => TaskBuilder::new().feature_id(feature).title(title),
+                    (None, None) => {
+                        return Err(OperationError::Validation(
+                            "A title is required unless --template is given".to_string(),
+                        ));
+                    }
+                };
 
+                if let Some(priority) = priority {
+                    builder = builder.priority(*priority);
+                }
                 if let Some(est) = estimate {
                     builder = builder.estimated_hours(*est);
                 }
@@ -317,43 +715,126 @@ impl Cli {
                     builder = builder.depends_on(dep);
                 }
 
-                let request = builder
-                    .build()
-                    .map_err(|e| OperationError::Validation(e.to_string()))?;
-                let task = tasks::create_task(db, request)?;
+                let task = match &template {
+                    Some(template) => templates::create_task_from_template(
+                        db,
+                        template,
+                        builder,
+                        &new_labels,
+                        "cli",
+                    )?,
+                    None => {
+                        let request = builder
+                            .build()
+                            .map_err(|e| OperationError::Validation(e.to_string()))?;
+                        let task = tasks::create_task(db, request)?;
+                        for label in &new_labels {
+                            labels::add_task_label(db, &task.id, label, "cli")?;
+                        }
+                        task
+                    }
+                };
                 println!("Created task: {}", task.id);
             }
             TaskCommands::Show { task_id, json } => {
                 let task = tasks::get_task(db, task_id)?;
                 let deps = tasks::get_task_dependencies(db, task_id)?;
                 let history = tasks::get_task_history(db, task_id)?;
+                let task_labels = labels::get_task_labels(db, task_id)?;
+                let checklist = checklists::get_checklist(db, task_id)?;
 
                 if *json {
                     println!("{}", serde_json::to_string_pretty(&task).unwrap());
                 } else {
-                    print!("{}", format_task_detail(&task, &deps, &history));
+                    print!(
+                        "{}",
+                        format_task_detail(&task, &task_labels, &checklist, &deps, &history)
+                    );
                 }
             }
-            TaskCommands::Move { task_id, status } => {
+            TaskCommands::Move {
+                task_id,
+                status,
+                override_wip,
+            } => {
                 let new_status: TaskStatus = status
                     .parse()
                     .map_err(|_| OperationError::Validation(format!("Invalid status: {}", status)))?;
-                let task = tasks::update_task_status(db, task_id, new_status, "cli")?;
+                let task =
+                    tasks::update_task_status(db, task_id, new_status, "cli", *override_wip)?;
                 println!("Moved {} to {}", task.id, task.status);
             }
-            TaskCommands::Assign { task_id, agent_id } => {
-                let task = tasks::assign_task(db, task_id, agent_id, "cli")?;
+            TaskCommands::Assign {
+                task_id,
+                agent_id,
+                override_wip,
+            } => {
+                let task = tasks::assign_task(db, task_id, agent_id, "cli", *override_wip)?;
                 println!(
                     "Assigned {} to {}",
                     task.id,
                     task.assigned_agent.unwrap_or_default()
                 );
             }
-            TaskCommands::Update { task_id, priority } => {
-                if let Some(p) = priority {
-                    let task = tasks::update_task_priority(db, task_id, *p, "cli")?;
+            TaskCommands::Update {
+                task_id,
+                priority,
+                title,
+                description,
+                estimate,
+                clear_estimate,
+                feature,
+            } => {
+                let request = UpdateTaskRequest {
+                    title: title.clone(),
+                    description: description
+                        .as_ref()
+                        .map(|d| Some(d.clone()).filter(|d| !d.is_empty())),
+                    priority: *priority,
+                    estimated_hours: if *clear_estimate {
+                        Some(None)
+                    } else {
+                        estimate.map(Some)
+                    },
+                    feature_id: feature.clone(),
+                };
+                if request.title.is_none()
+                    && request.description.is_none()
+                    && request.priority.is_none()
+                    && request.estimated_hours.is_none()
+                    && request.feature_id.is_none()
+                {
+                    return Err(OperationError::Validation(
+                        "Nothing to update: pass --priority, --title, --description, \
+                         --estimate, --clear-estimate, or --feature"
+                            .to_string(),
+                    ));
+                }
+
+                let task = tasks::update_task(db, task_id, &request, "cli")?;
+                if request.priority.is_some() {
                     println!("Updated {} priority to {}", task.id, task.priority);
                 }
+                if request.title.is_some() {
+                    println!("Updated {} title to {}", task.id, task.title);
+                }
+                if request.description.is_some() {
+                    println!(
+                        "Updated {} description{}",
+                        task.id,
+                        if task.description.is_none() { " (cleared)" } else { "" }
+                    );
+                }
+                match task.estimated_hours {
+                    Some(e) if estimate.is_some() => {
+                        println!("Updated {} estimate to {}h", task.id, e)
+                    }
+                    None if *clear_estimate => println!("Cleared {} estimate", task.id),
+                    _ => {}
+                }
+                if request.feature_id.is_some() {
+                    println!("Moved {} to feature {}", task.id, task.feature_id);
+                }
             }
             TaskCommands::History { task_id } => {
                 let history = tasks::get_task_history(db, task_id)?;
@@ -368,6 +849,111 @@ impl Cli {
                     );
                 }
             }
+            TaskCommands::Delete { task_id, force } => {
+                tasks::delete_task(db, task_id, *force)?;
+                println!("Deleted task: {}", task_id);
+            }
+            TaskCommands::Archive { task_id } => {
+                let task = tasks::archive_task(db, task_id, "cli")?;
+                println!("Archived task: {} - {}", task.id, task.title);
+            }
+            TaskCommands::Log { task_id, hours } => {
+                let entry = time::log_time(db, task_id, *hours, "cli")?;
+                let task = tasks::get_task(db, task_id)?;
+                println!(
+                    "Logged {:.2}h on {} (total {:.2}h)",
+                    entry.hours.unwrap_or(0.0),
+                    task.id,
+                    task.actual_hours.unwrap_or(0.0)
+                );
+            }
+            TaskCommands::Timer { command } => match command {
+                TimerCommands::Start { task_id } => {
+                    time::start_timer(db, task_id, "cli")?;
+                    println!("Started timer on {}", task_id);
+                }
+                TimerCommands::Stop { task_id } => {
+                    let entry = time::stop_timer(db, task_id, "cli")?;
+                    let task = tasks::get_task(db, task_id)?;
+                    println!(
+                        "Stopped timer on {}: {:.2}h (total {:.2}h)",
+                        task.id,
+                        entry.hours.unwrap_or(0.0),
+                        task.actual_hours.unwrap_or(0.0)
+                    );
+                }
+            },
+            TaskCommands::Import { file, feature } => {
+                let format = plans::PlanFormat::from_path(file)?;
+                let text = std::fs::read_to_string(file).map_err(|e| {
+                    OperationError::Validation(format!("Cannot read {}: {}", file, e))
+                })?;
+                let plan = plans::parse_plan(&text, format)?;
+                let created = plans::import_plan(db, &plan, feature.as_deref(), "cli")?;
+
+                println!("Created {} tasks:", created.len());
+                for imported in &created {
+                    match &imported.key {
+                        Some(key) => println!(
+                            "  {} <- {}: {}",
+                            imported.task.id, key, imported.task.title
+                        ),
+                        None => println!("  {}: {}", imported.task.id, imported.task.title),
+                    }
+                }
+            }
+            TaskCommands::Export {
+                feature,
+                format,
+                output,
+            } => {
+                let format = match (format, output) {
+                    (Some(name), _) => plans::PlanFormat::from_name(name)?,
+                    (None, Some(path)) => plans::PlanFormat::from_path(path)?,
+                    (None, None) => plans::PlanFormat::Json,
+                };
+                let plan = plans::export_plan(db, feature)?;
+                let text = plans::write_plan(&plan, format);
+
+                match output {
+                    Some(path) => {
+                        std::fs::write(path, text).map_err(|e| {
+                            OperationError::Validation(format!("Cannot write {}: {}", path, e))
+                        })?;
+                        println!("Exported {} tasks to {}", plan.tasks.len(), path);
+                    }
+                    None => print!("{}", text),
+                }
+            }
+            TaskCommands::Check { command } => match command {
+                CheckCommands::Add { task_id, text } => {
+                    let item = checklists::add_checklist_item(db, task_id, text)?;
+                    println!("Added item {} to {}", item.position, task_id);
+                }
+                CheckCommands::Toggle { task_id, item } => {
+                    let item = checklists::toggle_checklist_item(db, task_id, *item)?;
+                    let (done, total) =
+                        checklists::checklist_progress(&checklists::get_checklist(db, task_id)?);
+                    println!(
+                        "{} {}. {} ({}/{} done)",
+                        if item.done { "[x]" } else { "[ ]" },
+                        item.position,
+                        item.text,
+                        done,
+                        total
+                    );
+                }
+                CheckCommands::List { task_id, json } => {
+                    tasks::get_task(db, task_id)?;
+                    let items = checklists::get_checklist(db, task_id)?;
+
+                    if *json {
+                        println!("{}", serde_json::to_string_pretty(&items).unwrap());
+                    } else {
+                        print!("{}", format_checklist(&items));
+                    }
+                }
+            },
         }
         Ok(())
     }
@@ -470,6 +1056,14 @@ impl Cli {
                 };
                 let blocker = blockers::add_blocker(db, request)?;
                 println!("Added blocker: {}", blocker.id);
+                let task = tasks::get_task(db, task_id)?;
+                if task.status != TaskStatus::Blocked {
+                    println!(
+                        "Task {} was not moved and stays in {} \
+                         (only in-progress tasks can be blocked)",
+                        task.id, task.status
+                    );
+                }
             }
             BlockerCommands::Resolve { blocker_id, notes } => {
                 let blocker = blockers::resolve_blocker(db, blocker_id, notes.as_deref())?;
@@ -516,4 +1110,281 @@ impl Cli {
         }
         Ok(())
     }
+    fn handle_label_command(
+        &self,
+        db: &Database,
+        command: &LabelCommands,
+        _global_json: bool,
+    ) -> Result<(), OperationError> {
+        match command {
+            LabelCommands::List { json } => {
+                let label_list = labels::list_labels(db)?;
+
+                if *json {
+                    println!("{}", serde_json::to_string_pretty(&label_list).unwrap());
+                } else {
+                    print!("{}", format_labels_table(&label_list));
+                }
+            }
+            LabelCommands::Add {
+                task_id,
+                labels: new_labels,
+            } => {
+                for label in new_labels {
+                    labels::add_task_label(db, task_id, label, "cli")?;
+                }
+                let current = labels::get_task_labels(db, task_id)?;
+                println!("Labels on {}: {}", task_id, current.join(", "));
+            }
+            LabelCommands::Remove { task_id, label } => {
+                labels::remove_task_label(db, task_id, label, "cli")?;
+                println!("Removed label '{}' from {}", label, task_id);
+            }
+            LabelCommands::Metrics { feature, json } => {
+                let label_metrics = metrics::get_label_metrics(db, feature.as_deref())?;
+
+                if *json {
+                    println!("{}", serde_json::to_string_pretty(&label_metrics).unwrap());
+                } else {
+                    print!("{}", format_label_metrics(&label_metrics));
+                }
+            }
+        }
+        Ok(())
+    }
+    fn handle_template_command(
+        &self,
+        db: &Database,
+        command: &TemplateCommands,
+        _global_json: bool,
+    ) -> Result<(), OperationError> {
+        match command {
+            TemplateCommands::List { json } => {
+                let template_list = templates::list_templates(db)?;
+
+                if *json {
+                    println!("{}", serde_json::to_string_pretty(&template_list).unwrap());
+                } else {
+                    print!("{}", format_templates_table(&template_list));
+                }
+            }
+            TemplateCommands::Create {
+                name,
+                title,
+                description,
+                estimate,
+                priority,
+                labels: template_labels,
+                depends_on,
+            } => {
+                let template = templates::create_template(
+                    db,
+                    TaskTemplate {
+                        name: name.clone(),
+                        title_pattern: title.clone(),
+                        description: description.clone(),
+                        estimated_hours: *estimate,
+                        priority: *priority,
+                        labels: template_labels.clone(),
+                        depends_on: depends_on.clone(),
+                    },
+                )?;
+                println!("Created template: {}", template.name);
+            }
+            TemplateCommands::Delete { name } => {
+                templates::delete_template(db, name)?;
+                println!("Deleted template: {}", name);
+            }
+        }
+        Ok(())
+    }
+    fn handle_sprint_command(
+        &self,
+        db: &Database,
+        command: &SprintCommands,
+    ) -> Result<(), OperationError> {
+        match command {
+            SprintCommands::List { json } => {
+                let summaries = iterations::list_iterations(db)?
+                    .iter()
+                    .map(|i| metrics::get_iteration_summary(db, &i.id))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                if *json {
+                    println!("{}", serde_json::to_string_pretty(&summaries).unwrap());
+                } else {
+                    print!("{}", format_iterations_table(&summaries));
+                }
+            }
+            SprintCommands::Create { name, start, end } => {
+                let sprint =
+                    iterations::create_iteration(db, name, parse_date(start)?, parse_date(end)?)?;
+                println!(
+                    "Created sprint: {} ({} to {})",
+                    sprint.id, sprint.start_date, sprint.end_date
+                );
+            }
+            SprintCommands::Add {
+                sprint_id,
+                task_ids,
+                points,
+            } => {
+                for task_id in task_ids {
+                    iterations::add_iteration_task(db, sprint_id, task_id, *points, "cli")?;
+                }
+                println!("Committed {} tasks to {}", task_ids.len(), sprint_id);
+            }
+            SprintCommands::Remove { sprint_id, task_id } => {
+                iterations::remove_iteration_task(db, sprint_id, task_id, "cli")?;
+                println!("Removed {} from {}", task_id, sprint_id);
+            }
+            SprintCommands::Report {
+                sprint_id,
+                window,
+                json,
+            } => {
+                let sprint_id = match sprint_id {
+                    Some(id) => id.clone(),
+                    None => {
+                        let today = chrono::Utc::now().date_naive();
+                        iterations::current_iteration(db, today)?
+                            .map(|i| i.id)
+                            .ok_or_else(|| {
+                                OperationError::NotFound(
+                                    "No sprint running today; pass a sprint ID".to_string(),
+                                )
+                            })?
+                    }
+                };
+                let report = metrics::get_sprint_report(db, &sprint_id, *window)?;
+
+                if *json {
+                    println!("{}", serde_json::to_string_pretty(&report).unwrap());
+                } else {
+                    print!("{}", format_sprint_report(&report));
+                }
+            }
+            SprintCommands::Delete { sprint_id } => {
+                iterations::delete_iteration(db, sprint_id)?;
+                println!("Deleted sprint: {}", sprint_id);
+            }
+        }
+        Ok(())
+    }
+    fn handle_wip_command(
+        &self,
+        db: &Database,
+        command: &WipCommands,
+    ) -> Result<(), OperationError> {
+        let parse_status = |status: &str| -> Result<TaskStatus, OperationError> {
+            status
+                .parse()
+                .map_err(|_| OperationError::Validation(format!("Invalid status: {}", status)))
+        };
+
+        match command {
+            WipCommands::List { json } => {
+                let limits = wip::list_wip_limits(db)?;
+
+                if *json {
+                    println!("{}", serde_json::to_string_pretty(&limits).unwrap());
+                } else {
+                    print!("{}", format_wip_limits_table(&limits));
+                }
+            }
+            WipCommands::Set {
+                status,
+                max_tasks,
+                agent,
+            } => {
+                let limit =
+                    wip::set_wip_limit(db, parse_status(status)?, agent.as_deref(), *max_tasks)?;
+                match &limit.agent_id {
+                    Some(agent) => println!(
+                        "WIP limit for {} in {}: {}",
+                        agent, limit.status, limit.max_tasks
+                    ),
+                    None => println!("WIP limit for {}: {}", limit.status, limit.max_tasks),
+                }
+            }
+            WipCommands::Clear { status, agent } => {
+                let status = parse_status(status)?;
+                wip::clear_wip_limit(db, status, agent.as_deref())?;
+                println!("Cleared WIP limit for {}", status);
+            }
+        }
+        Ok(())
+    }
+    fn handle_export_command(
+        &self,
+        db: &Database,
+        command: &ExportCommands,
+    ) -> Result<(), OperationError> {
+        match command {
+            ExportCommands::Markdown {
+                feature,
+                group_by,
+                output,
+            } => {
+                let grouping = export::MarkdownGrouping::from_name(group_by)?;
+                let markdown = export::markdown_report(db, feature.as_deref(), grouping)?;
+                write_output(output.as_deref(), &markdown)?;
+            }
+            ExportCommands::Json { output } => {
+                let board = backup::export_board(db)?;
+                let json = serde_json::to_string_pretty(&board).unwrap();
+                write_output(output.as_deref(), &format!("{}\n", json))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_import_command(
+        &self,
+        db: &Database,
+        command: &ImportCommands,
+    ) -> Result<(), OperationError> {
+        match command {
+            ImportCommands::Json { file, replace } => {
+                let text = std::fs::read_to_string(file).map_err(|e| {
+                    OperationError::Validation(format!("Cannot read {}: {}", file, e))
+                })?;
+                let board: BoardBackup = serde_json::from_str(&text).map_err(|e| {
+                    OperationError::Validation(format!("Invalid backup {}: {}", file, e))
+                })?;
+                backup::import_board(db, &board, *replace)?;
+
+                println!(
+                    "Imported {} features, {} tasks, {} blockers, and {} comments from {}",
+                    board.features.len(),
+                    board.tasks.len(),
+                    board.blockers.len(),
+                    board.task_comments.len(),
+                    file
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parse a YYYY-MM-DD date argument
+fn parse_date(value: &str) -> Result<chrono::NaiveDate, OperationError> {
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        OperationError::Validation(format!("Invalid date '{}'; use YYYY-MM-DD", value))
+    })
+}
+
+/// Print `text`, or write it to `path` and say so
+fn write_output(path: Option<&str>, text: &str) -> Result<(), OperationError> {
+    match path {
+        Some(path) => {
+            std::fs::write(path, text).map_err(|e| {
+                OperationError::Validation(format!("Cannot write {}: {}", path, e))
+            })?;
+            println!("Wrote {}", path);
+        }
+        None => print!("{}", text),
+    }
+    Ok(())
 }
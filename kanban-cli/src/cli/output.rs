@@ -1,7 +1,10 @@
 //! Output formatting for CLI commands
 
-use crate::models::{AgentWorkload, Blocker, Feature, FeatureSummary, Task, TaskHistory};
-use crate::operations::metrics::FeatureMetrics;
+use crate::models::{
+    AgentWorkload, Blocker, ChecklistItem, Feature, FeatureSummary, Label, Task, TaskHistory,
+    TaskTemplate, WipLimit,
+};
+use crate::operations::metrics::{FeatureMetrics, IterationSummary, LabelMetrics, SprintReport};
 use crate::state_machine::TaskStatus;
 
 /// Output format options
@@ -12,6 +15,16 @@ pub enum OutputFormat {
     Json,
 }
 
+impl OutputFormat {
+    /// Parse a format name, falling back to table output
+    pub fn from_name(name: &str) -> Self {
+        match name.to_lowercase().as_str() {
+            "json" => OutputFormat::Json,
+            _ => OutputFormat::Table,
+        }
+    }
+}
+
 /// Format tasks as a table
 pub fn format_tasks_table(tasks: &[Task]) -> String {
     if tasks.is_empty() {
@@ -53,7 +66,13 @@ pub fn format_tasks_table(tasks: &[Task]) -> String {
 }
 
 /// Format a single task detail
-pub fn format_task_detail(task: &Task, dependencies: &[Task], history: &[TaskHistory]) -> String {
+pub fn format_task_detail(
+    task: &Task,
+    labels: &[String],
+    checklist: &[ChecklistItem],
+    dependencies: &[Task],
+    history: &[TaskHistory],
+) -> String {
     let mut output = String::new();
 
     output.push_str(&format!("Task: {}\n", task.id));
@@ -79,6 +98,9 @@ pub fn format_task_detail(task: &Task, dependencies: &[Task], history: &[TaskHis
             .map(|h| format!("{:.1}h", h))
             .unwrap_or_else(|| "-".to_string())
     ));
+    if !labels.is_empty() {
+        output.push_str(&format!("Labels:      {}\n", labels.join(", ")));
+    }
 
     if let Some(desc) = &task.description {
         output.push('\n');
@@ -87,6 +109,13 @@ pub fn format_task_detail(task: &Task, dependencies: &[Task], history: &[TaskHis
         output.push('\n');
     }
 
+    if !checklist.is_empty() {
+        let done = checklist.iter().filter(|i| i.done).count();
+        output.push('\n');
+        output.push_str(&format!("Checklist ({}/{}):\n", done, checklist.len()));
+        output.push_str(&format_checklist(checklist));
+    }
+
     if !dependencies.is_empty() {
         output.push('\n');
         output.push_str("Dependencies:\n");
@@ -216,6 +245,236 @@ pub fn format_feature_metrics(metrics: &FeatureMetrics) -> String {
     output
 }
 
+/// Format checklist items as "[x] 1. text" lines
+pub fn format_checklist(items: &[ChecklistItem]) -> String {
+    if items.is_empty() {
+        return "No checklist items.\n".to_string();
+    }
+
+    items
+        .iter()
+        .map(|item| {
+            format!(
+                "  {} {}. {}\n",
+                if item.done { "[x]" } else { "[ ]" },
+                item.position,
+                item.text
+            )
+        })
+        .collect()
+}
+
+/// Format labels as a table
+pub fn format_labels_table(labels: &[Label]) -> String {
+    if labels.is_empty() {
+        return "No labels found.".to_string();
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!("{:<25} {:<6}\n", "LABEL", "TASKS"));
+    output.push_str(&"-".repeat(32));
+    output.push('\n');
+
+    for label in labels {
+        output.push_str(&format!("{:<25} {:<6}\n", label.name, label.task_count));
+    }
+
+    output
+}
+
+/// Format per-label metrics as a table
+pub fn format_label_metrics(metrics: &[LabelMetrics]) -> String {
+    if metrics.is_empty() {
+        return "No labeled tasks found.".to_string();
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!(
+        "{:<20} {:<10} {:<8} {:<10} {:<10} {:<10}\n",
+        "LABEL", "DONE", "BLOCKED", "ESTIMATED", "ACTUAL", "REMAINING"
+    ));
+    output.push_str(&"-".repeat(73));
+    output.push('\n');
+
+    for m in metrics {
+        output.push_str(&format!(
+            "{:<20} {:<10} {:<8} {:<10} {:<10} {:<10}\n",
+            m.label,
+            format!("{}/{}", m.completed_tasks, m.total_tasks),
+            m.blocked_tasks,
+            format!("{:.1}h", m.estimated_hours),
+            format!("{:.1}h", m.actual_hours),
+            format!("{:.1}h", m.hours_remaining)
+        ));
+    }
+
+    output
+}
+
+/// Format templates as a table
+pub fn format_templates_table(templates: &[TaskTemplate]) -> String {
+    if templates.is_empty() {
+        return "No templates found.".to_string();
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!(
+        "{:<18} {:<32} {:<6} {:<20} {:<18}\n",
+        "NAME", "TITLE", "EST", "LABELS", "DEPENDS ON"
+    ));
+    output.push_str(&"-".repeat(98));
+    output.push('\n');
+
+    for template in templates {
+        let est = template
+            .estimated_hours
+            .map(|h| format!("{:.1}h", h))
+            .unwrap_or_else(|| "-".to_string());
+        let list = |names: &[String]| {
+            if names.is_empty() {
+                "-".to_string()
+            } else {
+                names.join(",")
+            }
+        };
+
+        output.push_str(&format!(
+            "{:<18} {:<32} {:<6} {:<20} {:<18}\n",
+            template.name,
+            template.title_pattern,
+            est,
+            list(&template.labels),
+            list(&template.depends_on)
+        ));
+    }
+
+    output
+}
+
+/// Format iterations as a table of committed and completed work
+pub fn format_iterations_table(summaries: &[IterationSummary]) -> String {
+    if summaries.is_empty() {
+        return "No sprints found.".to_string();
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!(
+        "{:<16} {:<20} {:<24} {:<8} {:<8} {:<14}\n",
+        "ID", "NAME", "DATES", "TASKS", "POINTS", "HOURS"
+    ));
+    output.push_str(&"-".repeat(95));
+    output.push('\n');
+
+    for s in summaries {
+        let i = &s.iteration;
+        output.push_str(&format!(
+            "{:<16} {:<20} {:<24} {:<8} {:<8} {:<14}\n",
+            i.id,
+            i.name,
+            format!("{} - {}", i.start_date, i.end_date),
+            format!("{}/{}", s.completed_tasks, s.committed_tasks),
+            format!("{}/{}", s.completed_points, s.committed_points),
+            format!("{:.1}/{:.1}h", s.completed_hours, s.committed_hours)
+        ));
+    }
+
+    output
+}
+
+/// Format a sprint report with its tasks and rolling velocity
+pub fn format_sprint_report(report: &SprintReport) -> String {
+    let s = &report.summary;
+    let i = &s.iteration;
+    let mut output = String::new();
+
+    output.push_str(&format!("Sprint: {} - {}\n", i.id, i.name));
+    output.push_str(&"-".repeat(50));
+    output.push('\n');
+    output.push_str(&format!(
+        "Dates: {} to {} ({} days)\n",
+        i.start_date,
+        i.end_date,
+        i.days()
+    ));
+
+    output.push_str("\nCommitted vs Completed:\n");
+    output.push_str(&format!(
+        "  Tasks:  {}/{}\n",
+        s.completed_tasks, s.committed_tasks
+    ));
+    output.push_str(&format!(
+        "  Points: {}/{}\n",
+        s.completed_points, s.committed_points
+    ));
+    output.push_str(&format!(
+        "  Hours:  {:.1}/{:.1}h estimated ({:.1}h logged)\n",
+        s.completed_hours, s.committed_hours, s.actual_hours
+    ));
+
+    output.push_str("\nVelocity:\n");
+    match &report.velocity {
+        Some(v) => {
+            output.push_str(&format!(
+                "  {:.1} points, {:.1}h per sprint (last {} finished)\n",
+                v.points,
+                v.hours,
+                v.history.len()
+            ));
+            for past in &v.history {
+                output.push_str(&format!(
+                    "  {:<16} {} points, {:.1}h\n",
+                    past.iteration.id, past.completed_points, past.completed_hours
+                ));
+            }
+        }
+        None => output.push_str("  No finished sprints yet\n"),
+    }
+
+    if !report.tasks.is_empty() {
+        output.push_str("\nTasks:\n");
+        for committed in &report.tasks {
+            let mark = if committed.completed_in(i) { "x" } else { " " };
+            let points = committed
+                .points
+                .map(|p| format!("{}pt", p))
+                .unwrap_or_else(|| "-".to_string());
+            output.push_str(&format!(
+                "  [{}] {:<15} {:<5} {} ({})\n",
+                mark,
+                committed.task.id,
+                points,
+                committed.task.title,
+                format_status(&committed.task.status)
+            ));
+        }
+    }
+
+    output
+}
+
+/// Format WIP limits as a table
+pub fn format_wip_limits_table(limits: &[WipLimit]) -> String {
+    if limits.is_empty() {
+        return "No WIP limits set.".to_string();
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!("{:<14} {:<30} {:<6}\n", "STATUS", "APPLIES TO", "MAX"));
+    output.push_str(&"-".repeat(52));
+    output.push('\n');
+
+    for limit in limits {
+        output.push_str(&format!(
+            "{:<14} {:<30} {:<6}\n",
+            limit.status.as_str(),
+            limit.agent_id.as_deref().unwrap_or("each feature column"),
+            limit.max_tasks
+        ));
+    }
+
+    output
+}
+
 /// Format blockers as a table
 pub fn format_blockers_table(blockers: &[Blocker]) -> String {
     if blockers.is_empty() {
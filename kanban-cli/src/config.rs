@@ -0,0 +1,106 @@
+//! Kanban configuration file
+//!
+//! The config file is optional JSON (default `kanban/config.json`); any
+//! missing section or key falls back to its default.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use thiserror::Error;
+
+/// Errors from loading configuration
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Failed to read config: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid config: {0}")]
+    Parse(#[from] serde_json::Error),
+}
+
+/// Top-level kanban configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Agent ID you work as, used by the TUI's "my tasks" filter
+    pub agent: Option<String>,
+    pub theme: ThemeConfig,
+}
+
+impl Config {
+    /// Load configuration from a file, using defaults if it does not exist
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+}
+
+/// TUI theme settings
+///
+/// `base` picks a built-in theme (`dark`, `light`, `high-contrast`); the
+/// other keys override individual colors by name (`red`, `lightblue`) or
+/// hex (`#ff8800`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    pub base: Option<String>,
+    pub columns: StatusColors,
+    pub statuses: StatusColors,
+    pub accent: Option<String>,
+    pub border: Option<String>,
+    pub selected_border: Option<String>,
+    pub selected_fg: Option<String>,
+    pub selected_bg: Option<String>,
+    pub muted: Option<String>,
+}
+
+/// Color overrides keyed by task status
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatusColors {
+    pub todo: Option<String>,
+    pub in_progress: Option<String>,
+    pub blocked: Option<String>,
+    pub in_qa: Option<String>,
+    pub done: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_config_uses_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::load(dir.path().join("config.json")).unwrap();
+        assert!(config.theme.base.is_none());
+    }
+
+    #[test]
+    fn test_partial_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        std::fs::write(
+            &path,
+            r#"{"theme": {"base": "light", "statuses": {"blocked": "magenta"}}}"#,
+        )
+        .unwrap();
+
+        let config = Config::load(&path).unwrap();
+        assert!(config.agent.is_none());
+        assert_eq!(config.theme.base.as_deref(), Some("light"));
+        assert_eq!(config.theme.statuses.blocked.as_deref(), Some("magenta"));
+        assert!(config.theme.statuses.todo.is_none());
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        std::fs::write(&path, "{ not json").unwrap();
+        assert!(Config::load(&path).is_err());
+    }
+}
@@ -44,6 +44,11 @@ impl Database {
         &mut self.conn
     }
 
+    /// Counter that changes whenever another connection commits to the database
+    pub fn data_version(&self) -> SqlResult<i64> {
+        self.conn.query_row("PRAGMA data_version", [], |row| row.get(0))
+    }
+
     /// Begin a transaction
     pub fn transaction(&mut self) -> SqlResult<rusqlite::Transaction<'_>> {
         self.conn.transaction()
@@ -80,4 +85,19 @@ mod tests {
             .unwrap();
         assert!(count >= 20, "Expected at least 20 default agents");
     }
+
+    #[test]
+    fn test_data_version_tracks_other_connections() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("kanban.db");
+        let watcher = Database::open(&path).unwrap();
+        let writer = Database::open(&path).unwrap();
+
+        let before = watcher.data_version().unwrap();
+        writer
+            .conn()
+            .execute("UPDATE agents SET max_concurrent_tasks = 5", [])
+            .unwrap();
+        assert_ne!(watcher.data_version().unwrap(), before);
+    }
 }
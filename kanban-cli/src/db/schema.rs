@@ -88,6 +88,105 @@ CREATE TABLE IF NOT EXISTS task_comments (
     FOREIGN KEY (task_id) REFERENCES tasks(id)
 );
 
+-- Time spent on tasks (ended_at is NULL while a timer runs)
+CREATE TABLE IF NOT EXISTS time_entries (
+    id TEXT PRIMARY KEY,
+    task_id TEXT NOT NULL,
+    started_at TIMESTAMP NOT NULL,
+    ended_at TIMESTAMP,
+    hours REAL,
+    logged_by TEXT NOT NULL,
+    FOREIGN KEY (task_id) REFERENCES tasks(id)
+);
+
+-- Checklist items on a task (lightweight subtasks)
+CREATE TABLE IF NOT EXISTS checklist_items (
+    id TEXT PRIMARY KEY,
+    task_id TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    text TEXT NOT NULL,
+    done INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    completed_at TIMESTAMP,
+    FOREIGN KEY (task_id) REFERENCES tasks(id)
+);
+
+-- Labels (cross-cutting tags such as bug or tech-debt)
+CREATE TABLE IF NOT EXISTS labels (
+    name TEXT PRIMARY KEY,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Labels attached to tasks
+CREATE TABLE IF NOT EXISTS task_labels (
+    task_id TEXT NOT NULL,
+    label TEXT NOT NULL,
+    PRIMARY KEY (task_id, label),
+    FOREIGN KEY (task_id) REFERENCES tasks(id),
+    FOREIGN KEY (label) REFERENCES labels(name)
+);
+
+-- Reusable task templates (labels and depends_on are JSON arrays of names)
+CREATE TABLE IF NOT EXISTS task_templates (
+    name TEXT PRIMARY KEY,
+    title_pattern TEXT NOT NULL,
+    description TEXT,
+    estimated_hours REAL,
+    priority INTEGER,
+    labels TEXT NOT NULL DEFAULT '[]',
+    depends_on TEXT NOT NULL DEFAULT '[]',
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Tasks created from a template
+CREATE TABLE IF NOT EXISTS template_tasks (
+    task_id TEXT PRIMARY KEY,
+    template_name TEXT NOT NULL,
+    FOREIGN KEY (task_id) REFERENCES tasks(id)
+);
+
+-- Iterations (time-boxed sprints; dates are inclusive YYYY-MM-DD)
+CREATE TABLE IF NOT EXISTS iterations (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    start_date TEXT NOT NULL,
+    end_date TEXT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Tasks committed to an iteration (a carried-over task stays in each one)
+CREATE TABLE IF NOT EXISTS iteration_tasks (
+    iteration_id TEXT NOT NULL,
+    task_id TEXT NOT NULL,
+    points INTEGER,
+    added_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (iteration_id, task_id),
+    FOREIGN KEY (iteration_id) REFERENCES iterations(id),
+    FOREIGN KEY (task_id) REFERENCES tasks(id)
+);
+
+-- Work-in-progress limits per status (agent_id '' limits the whole column)
+CREATE TABLE IF NOT EXISTS wip_limits (
+    status TEXT NOT NULL,
+    agent_id TEXT NOT NULL DEFAULT '',
+    max_tasks INTEGER NOT NULL,
+    PRIMARY KEY (status, agent_id)
+);
+
+-- Last number handed out per ID prefix, so deleted IDs are never reused
+CREATE TABLE IF NOT EXISTS id_sequences (
+    prefix TEXT PRIMARY KEY,
+    last INTEGER NOT NULL
+);
+
+-- Archived tasks (hidden from boards, lists, and metrics; history is kept)
+CREATE TABLE IF NOT EXISTS archived_tasks (
+    task_id TEXT PRIMARY KEY,
+    archived_by TEXT NOT NULL,
+    archived_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (task_id) REFERENCES tasks(id)
+);
+
 -- Workflow runs
 CREATE TABLE IF NOT EXISTS workflow_runs (
     id TEXT PRIMARY KEY,
@@ -137,6 +236,10 @@ CREATE INDEX IF NOT EXISTS idx_tasks_feature ON tasks(feature_id);
 CREATE INDEX IF NOT EXISTS idx_tasks_status ON tasks(status);
 CREATE INDEX IF NOT EXISTS idx_tasks_agent ON tasks(assigned_agent);
 CREATE INDEX IF NOT EXISTS idx_history_task ON task_history(task_id);
+CREATE INDEX IF NOT EXISTS idx_time_entries_task ON time_entries(task_id);
+CREATE INDEX IF NOT EXISTS idx_task_labels_label ON task_labels(label);
+CREATE INDEX IF NOT EXISTS idx_checklist_items_task ON checklist_items(task_id);
+CREATE INDEX IF NOT EXISTS idx_iteration_tasks_task ON iteration_tasks(task_id);
 CREATE INDEX IF NOT EXISTS idx_blockers_task ON blockers(task_id);
 CREATE INDEX IF NOT EXISTS idx_blockers_status ON blockers(status);
 CREATE INDEX IF NOT EXISTS idx_features_status ON features(status);
@@ -3,12 +3,10 @@
 use clap::Parser;
 
 mod cli;
-mod db;
-mod models;
-mod operations;
-mod state_machine;
 mod tui;
 
+use kanban::{config, db, models, operations, state_machine};
+
 use cli::Cli;
 
 fn main() {
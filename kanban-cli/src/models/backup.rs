@@ -0,0 +1,141 @@
+//! Board backup model for full JSON export and import
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Version written by this build; imports reject anything newer
+///
+/// Bump it only together with a way for `import_board` to read the older
+/// layout.
+pub const BOARD_BACKUP_VERSION: u32 = 1;
+
+/// One database row, keyed by column name
+pub type BackupRow = serde_json::Map<String, serde_json::Value>;
+
+/// Everything needed to rebuild a board on another machine
+///
+/// Rows are kept as they are stored so IDs, timestamps, and history survive
+/// the round trip unchanged. Tables missing from the file import as empty.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BoardBackup {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    #[serde(default)]
+    pub features: Vec<BackupRow>,
+    #[serde(default)]
+    pub agents: Vec<BackupRow>,
+    #[serde(default)]
+    pub tasks: Vec<BackupRow>,
+    #[serde(default)]
+    pub task_dependencies: Vec<BackupRow>,
+    #[serde(default)]
+    pub blockers: Vec<BackupRow>,
+    #[serde(default)]
+    pub task_comments: Vec<BackupRow>,
+    #[serde(default)]
+    pub task_history: Vec<BackupRow>,
+    #[serde(default)]
+    pub time_entries: Vec<BackupRow>,
+    #[serde(default)]
+    pub checklist_items: Vec<BackupRow>,
+    #[serde(default)]
+    pub labels: Vec<BackupRow>,
+    #[serde(default)]
+    pub task_labels: Vec<BackupRow>,
+    #[serde(default)]
+    pub task_templates: Vec<BackupRow>,
+    #[serde(default)]
+    pub template_tasks: Vec<BackupRow>,
+    #[serde(default)]
+    pub archived_tasks: Vec<BackupRow>,
+    #[serde(default)]
+    pub iterations: Vec<BackupRow>,
+    #[serde(default)]
+    pub iteration_tasks: Vec<BackupRow>,
+    #[serde(default)]
+    pub wip_limits: Vec<BackupRow>,
+    #[serde(default)]
+    pub id_sequences: Vec<BackupRow>,
+}
+
+impl BoardBackup {
+    /// Create an empty backup stamped with the current version and time
+    pub fn new() -> Self {
+        Self {
+            version: BOARD_BACKUP_VERSION,
+            exported_at: Utc::now(),
+            features: Vec::new(),
+            agents: Vec::new(),
+            tasks: Vec::new(),
+            task_dependencies: Vec::new(),
+            blockers: Vec::new(),
+            task_comments: Vec::new(),
+            task_history: Vec::new(),
+            time_entries: Vec::new(),
+            checklist_items: Vec::new(),
+            labels: Vec::new(),
+            task_labels: Vec::new(),
+            task_templates: Vec::new(),
+            template_tasks: Vec::new(),
+            archived_tasks: Vec::new(),
+            iterations: Vec::new(),
+            iteration_tasks: Vec::new(),
+            wip_limits: Vec::new(),
+            id_sequences: Vec::new(),
+        }
+    }
+
+    /// Rows of each table, named after the table they came from
+    pub fn tables(&self) -> [(&'static str, &Vec<BackupRow>); 18] {
+        [
+            ("features", &self.features),
+            ("agents", &self.agents),
+            ("tasks", &self.tasks),
+            ("task_dependencies", &self.task_dependencies),
+            ("blockers", &self.blockers),
+            ("task_comments", &self.task_comments),
+            ("task_history", &self.task_history),
+            ("time_entries", &self.time_entries),
+            ("checklist_items", &self.checklist_items),
+            ("labels", &self.labels),
+            ("task_labels", &self.task_labels),
+            ("task_templates", &self.task_templates),
+            ("template_tasks", &self.template_tasks),
+            ("archived_tasks", &self.archived_tasks),
+            ("iterations", &self.iterations),
+            ("iteration_tasks", &self.iteration_tasks),
+            ("wip_limits", &self.wip_limits),
+            ("id_sequences", &self.id_sequences),
+        ]
+    }
+
+    /// Mutable access to a table's rows, in the same order as `tables`
+    pub fn tables_mut(&mut self) -> [(&'static str, &mut Vec<BackupRow>); 18] {
+        [
+            ("features", &mut self.features),
+            ("agents", &mut self.agents),
+            ("tasks", &mut self.tasks),
+            ("task_dependencies", &mut self.task_dependencies),
+            ("blockers", &mut self.blockers),
+            ("task_comments", &mut self.task_comments),
+            ("task_history", &mut self.task_history),
+            ("time_entries", &mut self.time_entries),
+            ("checklist_items", &mut self.checklist_items),
+            ("labels", &mut self.labels),
+            ("task_labels", &mut self.task_labels),
+            ("task_templates", &mut self.task_templates),
+            ("template_tasks", &mut self.template_tasks),
+            ("archived_tasks", &mut self.archived_tasks),
+            ("iterations", &mut self.iterations),
+            ("iteration_tasks", &mut self.iteration_tasks),
+            ("wip_limits", &mut self.wip_limits),
+            ("id_sequences", &mut self.id_sequences),
+        ]
+    }
+}
+
+impl Default for BoardBackup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
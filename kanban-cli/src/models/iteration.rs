@@ -0,0 +1,100 @@
+//! Iteration model - time-boxed sprints that tasks are committed to
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::Task;
+
+/// A sprint with inclusive start and end dates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Iteration {
+    pub id: String,
+    pub name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Iteration {
+    /// Whether `date` falls inside the iteration
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start_date <= date && date <= self.end_date
+    }
+
+    /// Length in days, counting both ends
+    pub fn days(&self) -> i64 {
+        (self.end_date - self.start_date).num_days() + 1
+    }
+}
+
+/// A task committed to an iteration, with its story points if given
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IterationTask {
+    pub task: Task,
+    pub points: Option<i64>,
+}
+
+impl IterationTask {
+    /// Whether the task was done during the iteration
+    pub fn completed_in(&self, iteration: &Iteration) -> bool {
+        self.task.is_complete()
+            && self
+                .task
+                .completed_at
+                .is_some_and(|at| iteration.contains(at.date_naive()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state_machine::TaskStatus;
+
+    fn iteration() -> Iteration {
+        Iteration {
+            id: "sprint-1".to_string(),
+            name: "Sprint 1".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 3, 13).unwrap(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_iteration_dates() {
+        let sprint = iteration();
+        assert_eq!(sprint.days(), 12);
+        assert!(sprint.contains(sprint.end_date));
+        assert!(!sprint.contains(NaiveDate::from_ymd_opt(2026, 3, 14).unwrap()));
+    }
+
+    #[test]
+    fn test_completed_in() {
+        let sprint = iteration();
+        let mut task = Task::new("T-001".to_string(), "F-001".to_string(), "Test".to_string());
+        task.status = TaskStatus::Done;
+        task.completed_at = Some(
+            NaiveDate::from_ymd_opt(2026, 3, 16)
+                .unwrap()
+                .and_hms_opt(9, 0, 0)
+                .unwrap()
+                .and_utc(),
+        );
+        let mut committed = IterationTask { task, points: Some(3) };
+
+        // Finished after the sprint ended, so it was carried over
+        assert!(!committed.completed_in(&sprint));
+        let during = sprint.start_date.and_hms_opt(9, 0, 0).unwrap().and_utc();
+        committed.task.completed_at = Some(during);
+        assert!(committed.completed_in(&sprint));
+
+        // Finished before the sprint started, so the sprint did not deliver it
+        let before = NaiveDate::from_ymd_opt(2026, 2, 27)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap()
+            .and_utc();
+        committed.task.completed_at = Some(before);
+        assert!(!committed.completed_in(&sprint));
+    }
+}
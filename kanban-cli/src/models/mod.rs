@@ -1,13 +1,22 @@
 //! Data models for kanban entities
 
 mod agent;
+mod backup;
 mod blocker;
 mod feature;
+mod iteration;
+mod plan;
 mod task;
 mod workflow;
 
 pub use agent::{Agent, AgentWorkload};
-pub use blocker::{Blocker, CreateBlockerRequest};
+pub use backup::{BackupRow, BoardBackup, BOARD_BACKUP_VERSION};
+pub use blocker::{Blocker, BlockerDetail, CreateBlockerRequest};
 pub use feature::{CreateFeatureRequest, Feature, FeatureStatus, FeatureSummary};
-pub use task::{CreateTaskRequest, Task, TaskBuilder, TaskComment, TaskHistory};
+pub use iteration::{Iteration, IterationTask};
+pub use plan::{Plan, PlanTask};
+pub use task::{
+    ChecklistItem, CreateTaskRequest, Label, Task, TaskBuilder, TaskComment, TaskHistory,
+    TaskTemplate, TimeEntry, UpdateTaskRequest, WipLimit,
+};
 pub use workflow::{AgentExecution, WorkflowCheckpoint, WorkflowRun};
@@ -138,6 +138,19 @@ pub struct CreateTaskRequest {
     pub dependencies: Vec<String>,
 }
 
+/// Fields to change on an existing task; `None` leaves a field unchanged
+#[derive(Debug, Clone, Default)]
+pub struct UpdateTaskRequest {
+    pub title: Option<String>,
+    /// `Some(None)` clears the description
+    pub description: Option<Option<String>>,
+    pub priority: Option<i32>,
+    /// `Some(None)` clears the estimate
+    pub estimated_hours: Option<Option<f64>>,
+    /// Feature to move the task to
+    pub feature_id: Option<String>,
+}
+
 /// Task history entry for audit trail
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskHistory {
@@ -160,6 +173,65 @@ pub struct TaskComment {
     pub created_at: DateTime<Utc>,
 }
 
+/// One item of a task's checklist
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    pub id: String,
+    pub task_id: String,
+    /// 1-based position within the task's checklist
+    pub position: i64,
+    pub text: String,
+    pub done: bool,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// A label and the number of unarchived tasks carrying it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Label {
+    pub name: String,
+    pub task_count: i64,
+}
+
+/// Reusable blueprint for tasks every feature needs
+///
+/// `title_pattern` and `description` may use `{title}`, `{feature}`, and
+/// `{feature_id}` placeholders. `depends_on` names other templates: a new
+/// task depends on the feature's tasks created from those templates.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskTemplate {
+    pub name: String,
+    pub title_pattern: String,
+    pub description: Option<String>,
+    pub estimated_hours: Option<f64>,
+    pub priority: Option<i32>,
+    pub labels: Vec<String>,
+    pub depends_on: Vec<String>,
+}
+
+/// Work-in-progress limit for a status
+///
+/// Without an agent the limit caps the column on each feature's board; with
+/// one it caps that agent's tasks in the status across all features.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WipLimit {
+    pub status: TaskStatus,
+    pub agent_id: Option<String>,
+    pub max_tasks: i64,
+}
+
+/// A block of time spent on a task, logged directly or by a timer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeEntry {
+    pub id: String,
+    pub task_id: String,
+    pub started_at: DateTime<Utc>,
+    /// None while the timer is still running
+    pub ended_at: Option<DateTime<Utc>>,
+    pub hours: Option<f64>,
+    pub logged_by: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,305 @@
+//! Full board backup to and from JSON
+
+use std::collections::HashSet;
+
+use rusqlite::types::{Value, ValueRef};
+
+use crate::db::Database;
+use crate::models::{BackupRow, BoardBackup, BOARD_BACKUP_VERSION};
+
+use super::{OperationError, Result};
+
+/// Tables merged into the target board instead of requiring it to be empty
+///
+/// `init` seeds the default agents, and labels outlive the tasks using them.
+const MERGED_TABLES: &[&str] = &["agents", "labels"];
+
+/// Workflow run records, which are not part of the backup but point at
+/// features, tasks, and agents
+const WORKFLOW_TABLES: &[&str] = &["workflow_runs", "agent_executions", "workflow_checkpoints"];
+
+/// Convert a stored value to JSON
+fn value_to_json(value: ValueRef) -> Result<serde_json::Value> {
+    Ok(match value {
+        ValueRef::Null => serde_json::Value::Null,
+        ValueRef::Integer(i) => i.into(),
+        ValueRef::Real(f) => serde_json::Number::from_f64(f)
+            .map(serde_json::Value::Number)
+            .unwrap_or(serde_json::Value::Null),
+        ValueRef::Text(text) => String::from_utf8_lossy(text).into_owned().into(),
+        ValueRef::Blob(_) => {
+            return Err(OperationError::Validation(
+                "Cannot export binary values".to_string(),
+            ))
+        }
+    })
+}
+
+/// Convert a JSON value back to something SQLite can store
+fn json_to_value(value: &serde_json::Value) -> Result<Value> {
+    Ok(match value {
+        serde_json::Value::Null => Value::Null,
+        serde_json::Value::Bool(b) => Value::Integer(*b as i64),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => Value::Integer(i),
+            None => Value::Real(n.as_f64().unwrap_or_default()),
+        },
+        serde_json::Value::String(s) => Value::Text(s.clone()),
+        other => {
+            return Err(OperationError::Validation(format!(
+                "Unsupported value in backup: {}",
+                other
+            )))
+        }
+    })
+}
+
+fn read_table(db: &Database, table: &str) -> Result<Vec<BackupRow>> {
+    let mut stmt = db
+        .conn()
+        .prepare(&format!("SELECT * FROM {} ORDER BY rowid", table))?;
+    let columns: Vec<String> = stmt.column_names().iter().map(|c| c.to_string()).collect();
+
+    let mut rows = stmt.query([])?;
+    let mut out = Vec::new();
+    while let Some(row) = rows.next()? {
+        let mut record = BackupRow::new();
+        for (i, column) in columns.iter().enumerate() {
+            record.insert(column.clone(), value_to_json(row.get_ref(i)?)?);
+        }
+        out.push(record);
+    }
+    Ok(out)
+}
+
+fn table_columns(db: &Database, table: &str) -> Result<HashSet<String>> {
+    let mut stmt = db
+        .conn()
+        .prepare(&format!("SELECT name FROM pragma_table_info('{}')", table))?;
+    let columns = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<HashSet<_>>>()?;
+    Ok(columns)
+}
+
+/// Export the whole board
+///
+/// Covers features, tasks (archived ones included), dependencies, blockers,
+/// comments, history, agents, time entries, checklists, labels, templates,
+/// iterations, and WIP limits. Workflow run records are not part of the
+/// backup.
+pub fn export_board(db: &Database) -> Result<BoardBackup> {
+    let mut backup = BoardBackup::new();
+    for (table, rows) in backup.tables_mut() {
+        *rows = read_table(db, table)?;
+    }
+    Ok(backup)
+}
+
+/// Import a backup made with `export_board`
+///
+/// The target board must not have any features, tasks, or other board data
+/// unless `replace` is set, in which case the existing data is removed
+/// first. Replacing is refused while workflow run records exist, since they
+/// are not in the backup and would be left pointing at removed features and
+/// tasks. Agents and labels are always merged by key. Everything happens in
+/// one transaction, so a bad file leaves the board untouched.
+pub fn import_board(db: &Database, backup: &BoardBackup, replace: bool) -> Result<()> {
+    if backup.version == 0 || backup.version > BOARD_BACKUP_VERSION {
+        return Err(OperationError::Validation(format!(
+            "Unsupported backup version {} (this build reads up to {})",
+            backup.version, BOARD_BACKUP_VERSION
+        )));
+    }
+
+    if replace {
+        for table in WORKFLOW_TABLES {
+            let count: i64 = db.conn().query_row(
+                &format!("SELECT COUNT(*) FROM {}", table),
+                [],
+                |row| row.get(0),
+            )?;
+            if count > 0 {
+                return Err(OperationError::Validation(format!(
+                    "Board has workflow run records ({}), which the backup does not cover; \
+                     import into an empty database instead of using --replace",
+                    table.replace('_', " ")
+                )));
+            }
+        }
+    }
+
+    let tx = db.conn().unchecked_transaction()?;
+
+    // Children before parents so foreign keys hold while clearing
+    for (table, _) in backup.tables().into_iter().rev() {
+        if MERGED_TABLES.contains(&table) {
+            continue;
+        }
+        if replace {
+            db.conn().execute(&format!("DELETE FROM {}", table), [])?;
+        } else {
+            let count: i64 = db.conn().query_row(
+                &format!("SELECT COUNT(*) FROM {}", table),
+                [],
+                |row| row.get(0),
+            )?;
+            if count > 0 {
+                return Err(OperationError::Validation(format!(
+                    "Board already has {}; import into an empty database or use --replace",
+                    table.replace('_', " ")
+                )));
+            }
+        }
+    }
+
+    for (table, rows) in backup.tables() {
+        let known = table_columns(db, table)?;
+        let merge = MERGED_TABLES.contains(&table);
+
+        for row in rows {
+            if row.is_empty() {
+                return Err(OperationError::Validation(format!("Empty row in {}", table)));
+            }
+            if let Some(column) = row.keys().find(|c| !known.contains(*c)) {
+                return Err(OperationError::Validation(format!(
+                    "Unknown column '{}' in {}",
+                    column, table
+                )));
+            }
+
+            let columns: Vec<&str> = row.keys().map(|c| c.as_str()).collect();
+            let values = row.values().map(json_to_value).collect::<Result<Vec<_>>>()?;
+            let mut sql = format!(
+                "INSERT INTO {} ({}) VALUES ({})",
+                table,
+                columns.join(", "),
+                vec!["?"; columns.len()].join(", ")
+            );
+            if merge {
+                let updates: Vec<String> = columns
+                    .iter()
+                    .map(|c| format!("{} = excluded.{}", c, c))
+                    .collect();
+                sql.push_str(&format!(" ON CONFLICT DO UPDATE SET {}", updates.join(", ")));
+            }
+            db.conn().execute(&sql, rusqlite::params_from_iter(values))?;
+        }
+    }
+
+    tx.commit()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CreateBlockerRequest, CreateFeatureRequest, TaskBuilder};
+    use crate::operations::{blockers, checklists, features, labels, tasks, time};
+    use crate::state_machine::{BlockerType, TaskStatus};
+
+    fn setup_test_db() -> Database {
+        let db = Database::in_memory().unwrap();
+
+        features::create_feature(
+            &db,
+            CreateFeatureRequest {
+                name: "Test Feature".to_string(),
+                description: Some("Backup me".to_string()),
+                color: None,
+            },
+        )
+        .unwrap();
+
+        for title in ["Parse", "Validate", "Old work"] {
+            let request = TaskBuilder::new()
+                .feature_id("test-feature")
+                .title(title)
+                .estimated_hours(1.5)
+                .build()
+                .unwrap();
+            tasks::create_task(&db, request).unwrap();
+        }
+        tasks::add_task_dependency(&db, "T-test-feature-002", "T-test-feature-001").unwrap();
+        tasks::update_task_status(&db, "T-test-feature-001", TaskStatus::InProgress, "test", false)
+            .unwrap();
+        tasks::add_task_comment(&db, "T-test-feature-001", "test", "Looks good").unwrap();
+        tasks::archive_task(&db, "T-test-feature-003", "test").unwrap();
+        blockers::add_blocker(
+            &db,
+            CreateBlockerRequest {
+                task_id: "T-test-feature-002".to_string(),
+                blocker_type: BlockerType::Technical,
+                description: "Waiting on parser".to_string(),
+                blocking_task_id: Some("T-test-feature-001".to_string()),
+            },
+        )
+        .unwrap();
+        labels::add_task_label(&db, "T-test-feature-001", "bug", "test").unwrap();
+        checklists::add_checklist_item(&db, "T-test-feature-001", "Write tests").unwrap();
+        time::log_time(&db, "T-test-feature-001", 0.5, "test").unwrap();
+
+        db
+    }
+
+    #[test]
+    fn test_board_round_trip() {
+        let db = setup_test_db();
+        let backup = export_board(&db).unwrap();
+        assert_eq!(backup.version, BOARD_BACKUP_VERSION);
+        assert_eq!(backup.tasks.len(), 3);
+        assert_eq!(backup.task_dependencies.len(), 1);
+        assert_eq!(backup.archived_tasks.len(), 1);
+
+        let json = serde_json::to_string_pretty(&backup).unwrap();
+        let parsed: BoardBackup = serde_json::from_str(&json).unwrap();
+
+        let target = Database::in_memory().unwrap();
+        import_board(&target, &parsed, false).unwrap();
+        let restored = export_board(&target).unwrap();
+        assert_eq!(restored.tables(), backup.tables());
+
+        let task = tasks::get_task(&target, "T-test-feature-001").unwrap();
+        assert_eq!(task.actual_hours, Some(0.5));
+        assert!(tasks::is_task_archived(&target, "T-test-feature-003").unwrap());
+        // New IDs continue after the imported ones
+        let next = tasks::generate_task_id(&target, "test-feature").unwrap();
+        assert_eq!(next, "T-test-feature-004");
+    }
+
+    #[test]
+    fn test_import_board_checks() {
+        let db = setup_test_db();
+        let backup = export_board(&db).unwrap();
+
+        assert!(matches!(
+            import_board(&db, &backup, false),
+            Err(OperationError::Validation(_))
+        ));
+        import_board(&db, &backup, true).unwrap();
+        assert_eq!(export_board(&db).unwrap().tables(), backup.tables());
+
+        let mut newer = backup.clone();
+        newer.version = BOARD_BACKUP_VERSION + 1;
+        assert!(import_board(&db, &newer, true).is_err());
+
+        let mut unknown = backup.clone();
+        unknown.tasks[0].insert("sprint".to_string(), "S1".into());
+        assert!(import_board(&db, &unknown, true).is_err());
+        // The failed import rolled back
+        assert_eq!(tasks::get_task(&db, "T-test-feature-001").unwrap().title, "Parse");
+
+        // Workflow runs are not in the backup, so replacing would orphan them
+        db.conn()
+            .execute(
+                "INSERT INTO workflow_runs (id, feature_id, status, started_at) \
+                 VALUES ('run-1', 'test-feature', 'completed', '2026-03-02T09:00:00Z')",
+                [],
+            )
+            .unwrap();
+        assert!(matches!(
+            import_board(&db, &backup, true),
+            Err(OperationError::Validation(_))
+        ));
+    }
+}
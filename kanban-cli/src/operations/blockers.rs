@@ -5,7 +5,7 @@ use rusqlite::{params, Row};
 
 use crate::db::Database;
 use crate::models::{Blocker, CreateBlockerRequest};
-use crate::state_machine::{BlockerStatus, BlockerType, TaskStatus};
+use crate::state_machine::{BlockerStatus, BlockerType, StateMachine, TaskStatus};
 
 use super::tasks::{get_task, update_task_status};
 use super::{OperationError, Result};
@@ -55,6 +55,10 @@ pub fn generate_blocker_id(db: &Database) -> Result<String> {
 }
 
 /// Add a blocker to a task
+///
+/// The task moves to Blocked when the state machine allows it, which is
+/// only from in progress. Tasks in other states keep their status. The move
+/// is made by the system and skips WIP limits.
 pub fn add_blocker(db: &Database, request: CreateBlockerRequest) -> Result<Blocker> {
     // Verify task exists
     let task = get_task(db, &request.task_id)?;
@@ -78,9 +82,10 @@ pub fn add_blocker(db: &Database, request: CreateBlockerRequest) -> Result<Block
         ],
     )?;
 
-    // Auto-transition task to blocked if it's in progress
-    if task.status == TaskStatus::InProgress {
-        update_task_status(db, &request.task_id, TaskStatus::Blocked, "system")?;
+    // Auto-transition task to blocked (WIP limits only apply to moves
+    // people make)
+    if StateMachine::can_transition(&task.status, &TaskStatus::Blocked) {
+        update_task_status(db, &request.task_id, TaskStatus::Blocked, "system", true)?;
     }
 
     get_blocker(db, &blocker_id)
@@ -109,6 +114,7 @@ pub fn list_active_blockers(db: &Database, feature_id: Option<&str>) -> Result<V
         SELECT b.* FROM blockers b
         JOIN tasks t ON b.task_id = t.id
         WHERE b.status = 'active' AND t.feature_id = ?
+          AND t.id NOT IN (SELECT task_id FROM archived_tasks)
         ORDER BY b.created_at DESC
         "#
     } else {
@@ -141,6 +147,10 @@ pub fn list_task_blockers(db: &Database, task_id: &str) -> Result<Vec<Blocker>>
 }
 
 /// Resolve a blocker
+///
+/// When the task's last active blocker is resolved, a blocked task moves
+/// back to in progress. That move is made by the system and skips WIP
+/// limits, so the task may push its column or agent over a limit.
 pub fn resolve_blocker(
     db: &Database,
     blocker_id: &str,
@@ -165,7 +175,7 @@ pub fn resolve_blocker(
     if active_count == 0 {
         let task = get_task(db, &blocker.task_id)?;
         if task.status == TaskStatus::Blocked {
-            update_task_status(db, &blocker.task_id, TaskStatus::InProgress, "system")?;
+            update_task_status(db, &blocker.task_id, TaskStatus::InProgress, "system", true)?;
         }
     }
 
@@ -213,7 +223,7 @@ mod tests {
         let task = tasks::create_task(&db, request).unwrap();
 
         // Move task to in-progress
-        tasks::update_task_status(&db, &task.id, TaskStatus::InProgress, "test").unwrap();
+        tasks::update_task_status(&db, &task.id, TaskStatus::InProgress, "test", false).unwrap();
 
         (db, task.id)
     }
@@ -241,6 +251,28 @@ mod tests {
         assert_eq!(task.status, TaskStatus::Blocked);
     }
 
+    #[test]
+    fn test_add_blocker_outside_in_progress() {
+        let (db, task_id) = setup_test_db();
+        update_task_status(&db, &task_id, TaskStatus::InQa, "test", false).unwrap();
+
+        add_blocker(
+            &db,
+            CreateBlockerRequest {
+                task_id: task_id.clone(),
+                blocker_type: BlockerType::External,
+                description: "Waiting for test data".to_string(),
+                blocking_task_id: None,
+            },
+        )
+        .unwrap();
+
+        // In QA cannot move to blocked, so the task keeps its status
+        let task = get_task(&db, &task_id).unwrap();
+        assert_eq!(task.status, TaskStatus::InQa);
+        assert_eq!(list_active_blockers(&db, None).unwrap().len(), 1);
+    }
+
     #[test]
     fn test_resolve_blocker() {
         let (db, task_id) = setup_test_db();
@@ -0,0 +1,196 @@
+//! Checklist operations
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use rusqlite::{params, Row};
+use uuid::Uuid;
+
+use crate::db::Database;
+use crate::models::ChecklistItem;
+
+use super::tasks::get_task;
+use super::{OperationError, Result};
+
+/// Parse a checklist item from a database row
+fn checklist_item_from_row(row: &Row) -> rusqlite::Result<ChecklistItem> {
+    Ok(ChecklistItem {
+        id: row.get("id")?,
+        task_id: row.get("task_id")?,
+        position: row.get("position")?,
+        text: row.get("text")?,
+        done: row.get::<_, i64>("done")? != 0,
+        created_at: parse_datetime(row.get::<_, String>("created_at")?),
+        completed_at: row
+            .get::<_, Option<String>>("completed_at")?
+            .map(parse_datetime),
+    })
+}
+
+fn parse_datetime(s: String) -> chrono::DateTime<Utc> {
+    chrono::DateTime::parse_from_rfc3339(&s)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|_| {
+            chrono::NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S")
+                .map(|dt| dt.and_utc())
+                .unwrap_or_else(|_| Utc::now())
+        })
+}
+
+/// Count completed and total items, e.g. (3, 5) for "3/5"
+pub fn checklist_progress(items: &[ChecklistItem]) -> (usize, usize) {
+    (items.iter().filter(|i| i.done).count(), items.len())
+}
+
+/// Add an item to the end of a task's checklist
+pub fn add_checklist_item(db: &Database, task_id: &str, text: &str) -> Result<ChecklistItem> {
+    get_task(db, task_id)?;
+
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(OperationError::Validation(
+            "Checklist item cannot be empty".to_string(),
+        ));
+    }
+
+    let position: i64 = db.conn().query_row(
+        "SELECT COALESCE(MAX(position), 0) + 1 FROM checklist_items WHERE task_id = ?",
+        params![task_id],
+        |row| row.get(0),
+    )?;
+    let id = Uuid::new_v4().to_string();
+    let now = Utc::now().to_rfc3339();
+
+    db.conn().execute(
+        "INSERT INTO checklist_items (id, task_id, position, text, created_at) VALUES (?, ?, ?, ?, ?)",
+        params![id, task_id, position, text, now],
+    )?;
+
+    get_checklist_item(db, task_id, position)
+}
+
+/// Get a checklist item by its position in the task's checklist
+pub fn get_checklist_item(db: &Database, task_id: &str, position: i64) -> Result<ChecklistItem> {
+    db.conn()
+        .query_row(
+            "SELECT * FROM checklist_items WHERE task_id = ? AND position = ?",
+            params![task_id, position],
+            checklist_item_from_row,
+        )
+        .map_err(|e| match e {
+            rusqlite::Error::QueryReturnedNoRows => OperationError::NotFound(format!(
+                "Checklist item {} not found on task {}",
+                position, task_id
+            )),
+            e => OperationError::Database(e),
+        })
+}
+
+/// Flip a checklist item between done and not done
+pub fn toggle_checklist_item(db: &Database, task_id: &str, position: i64) -> Result<ChecklistItem> {
+    let item = get_checklist_item(db, task_id, position)?;
+    let completed_at = if item.done {
+        None
+    } else {
+        Some(Utc::now().to_rfc3339())
+    };
+
+    db.conn().execute(
+        "UPDATE checklist_items SET done = ?, completed_at = ? WHERE id = ?",
+        params![!item.done, completed_at, item.id],
+    )?;
+
+    get_checklist_item(db, task_id, position)
+}
+
+/// Get a task's checklist in order
+pub fn get_checklist(db: &Database, task_id: &str) -> Result<Vec<ChecklistItem>> {
+    let mut stmt = db
+        .conn()
+        .prepare("SELECT * FROM checklist_items WHERE task_id = ? ORDER BY position")?;
+    let items = stmt
+        .query_map(params![task_id], checklist_item_from_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(items)
+}
+
+/// Get the checklists of every task in a feature, keyed by task ID
+pub fn get_feature_checklists(
+    db: &Database,
+    feature_id: &str,
+) -> Result<HashMap<String, Vec<ChecklistItem>>> {
+    let mut stmt = db.conn().prepare(
+        r#"
+        SELECT c.* FROM checklist_items c
+        JOIN tasks t ON c.task_id = t.id
+        WHERE t.feature_id = ?
+        ORDER BY c.task_id, c.position
+        "#,
+    )?;
+    let rows = stmt.query_map(params![feature_id], checklist_item_from_row)?;
+
+    let mut checklists: HashMap<String, Vec<ChecklistItem>> = HashMap::new();
+    for row in rows {
+        let item = row?;
+        checklists.entry(item.task_id.clone()).or_default().push(item);
+    }
+    Ok(checklists)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CreateFeatureRequest, TaskBuilder};
+    use crate::operations::{features, tasks};
+
+    fn setup_test_db() -> (Database, String) {
+        let db = Database::in_memory().unwrap();
+
+        features::create_feature(
+            &db,
+            CreateFeatureRequest {
+                name: "Test Feature".to_string(),
+                description: None,
+                color: None,
+            },
+        )
+        .unwrap();
+
+        let request = TaskBuilder::new()
+            .feature_id("test-feature")
+            .title("Test task")
+            .build()
+            .unwrap();
+        let task = tasks::create_task(&db, request).unwrap();
+
+        (db, task.id)
+    }
+
+    #[test]
+    fn test_checklist() {
+        let (db, task_id) = setup_test_db();
+
+        add_checklist_item(&db, &task_id, "Write tests").unwrap();
+        let second = add_checklist_item(&db, &task_id, "  Update docs ").unwrap();
+        assert_eq!(second.position, 2);
+        assert_eq!(second.text, "Update docs");
+        assert!(add_checklist_item(&db, &task_id, " ").is_err());
+
+        let toggled = toggle_checklist_item(&db, &task_id, 1).unwrap();
+        assert!(toggled.done);
+        assert!(toggled.completed_at.is_some());
+        assert_eq!(checklist_progress(&get_checklist(&db, &task_id).unwrap()), (1, 2));
+
+        let untoggled = toggle_checklist_item(&db, &task_id, 1).unwrap();
+        assert!(!untoggled.done);
+        assert!(untoggled.completed_at.is_none());
+
+        assert!(matches!(
+            toggle_checklist_item(&db, &task_id, 3),
+            Err(OperationError::NotFound(_))
+        ));
+
+        let by_task = get_feature_checklists(&db, "test-feature").unwrap();
+        assert_eq!(by_task[&task_id].len(), 2);
+    }
+}
@@ -0,0 +1,300 @@
+//! Board exports for sharing outside the CLI
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+
+use crate::db::Database;
+use crate::models::{Feature, Task};
+use crate::state_machine::{FeatureStatus, TaskStatus};
+
+use super::blockers::list_active_blockers;
+use super::features::{get_feature, list_features};
+use super::labels::get_feature_labels;
+use super::metrics::{get_feature_metrics, get_overall_metrics, FeatureMetrics};
+use super::tasks::list_tasks;
+use super::{OperationError, Result};
+
+/// How tasks are grouped into sections of the markdown export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MarkdownGrouping {
+    #[default]
+    Status,
+    Feature,
+}
+
+impl MarkdownGrouping {
+    /// Parse a grouping name ("status" or "feature")
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "status" => Ok(MarkdownGrouping::Status),
+            "feature" => Ok(MarkdownGrouping::Feature),
+            other => Err(OperationError::Validation(format!(
+                "Unknown grouping '{}'; use status or feature",
+                other
+            ))),
+        }
+    }
+}
+
+/// Section heading for a status
+fn status_heading(status: TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Todo => "To Do",
+        TaskStatus::InProgress => "In Progress",
+        TaskStatus::Blocked => "Blocked",
+        TaskStatus::InQa => "In QA",
+        TaskStatus::Done => "Done",
+    }
+}
+
+/// Make text safe for a markdown table cell
+fn cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+fn hours(value: Option<f64>) -> String {
+    value
+        .map(|h| format!("{:.1}h", h))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Render the board as a markdown document
+///
+/// Covers one feature, or every active feature when `feature_id` is None,
+/// with a metrics summary, task tables grouped by status or feature, and
+/// the active blockers. Archived tasks are left out.
+pub fn markdown_report(
+    db: &Database,
+    feature_id: Option<&str>,
+    grouping: MarkdownGrouping,
+) -> Result<String> {
+    let (title, features, metrics) = match feature_id {
+        Some(fid) => {
+            let feature = get_feature(db, fid)?;
+            let metrics = get_feature_metrics(db, fid)?;
+            (feature.name.clone(), vec![feature], metrics)
+        }
+        None => (
+            "All active features".to_string(),
+            list_features(db, Some(FeatureStatus::Active))?,
+            get_overall_metrics(db)?,
+        ),
+    };
+
+    let mut tasks: Vec<Task> = Vec::new();
+    let mut labels: HashMap<String, Vec<String>> = HashMap::new();
+    for feature in &features {
+        tasks.extend(list_tasks(db, Some(&feature.id), None, None, &[])?);
+        labels.extend(get_feature_labels(db, &feature.id)?);
+    }
+    let feature_names: HashMap<&str, &str> = features
+        .iter()
+        .map(|f| (f.id.as_str(), f.name.as_str()))
+        .collect();
+
+    let mut out = String::new();
+    out.push_str(&format!("# Kanban Board: {}\n\n", title));
+    out.push_str(&format!(
+        "_Generated {}_\n\n",
+        Utc::now().format("%Y-%m-%d %H:%M UTC")
+    ));
+    push_metrics(&mut out, &metrics);
+
+    let labels_of = |task: &Task| {
+        labels
+            .get(&task.id)
+            .map(|l| l.join(", "))
+            .unwrap_or_else(|| "-".to_string())
+    };
+    match grouping {
+        MarkdownGrouping::Status => {
+            for &status in TaskStatus::all() {
+                let group: Vec<&Task> = tasks.iter().filter(|t| t.status == status).collect();
+                out.push_str(&format!("## {} ({})\n\n", status_heading(status), group.len()));
+                let rows = group.iter().map(|t| {
+                    let feature = feature_names.get(t.feature_id.as_str()).unwrap_or(&"-");
+                    vec![cell(feature), labels_of(t)]
+                });
+                push_task_table(&mut out, &group, &["Feature", "Labels"], rows);
+            }
+        }
+        MarkdownGrouping::Feature => {
+            for feature in &features {
+                push_feature_section(&mut out, feature, &tasks, &labels_of);
+            }
+        }
+    }
+
+    let blockers: Vec<_> = match feature_id {
+        Some(fid) => list_active_blockers(db, Some(fid))?,
+        None => list_active_blockers(db, None)?
+            .into_iter()
+            .filter(|b| tasks.iter().any(|t| t.id == b.task_id))
+            .collect(),
+    };
+    out.push_str(&format!("## Blockers ({})\n\n", blockers.len()));
+    if blockers.is_empty() {
+        out.push_str("_No active blockers._\n");
+    } else {
+        out.push_str("| ID | Task | Type | Description | Since |\n");
+        out.push_str("|---|---|---|---|---|\n");
+        for blocker in &blockers {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                blocker.id,
+                blocker.task_id,
+                blocker.blocker_type,
+                cell(&blocker.description),
+                blocker.created_at.format("%Y-%m-%d")
+            ));
+        }
+    }
+
+    Ok(out)
+}
+
+fn push_metrics(out: &mut String, metrics: &FeatureMetrics) {
+    out.push_str("## Metrics\n\n");
+    out.push_str("| Metric | Value |\n|---|---|\n");
+    out.push_str(&format!(
+        "| Progress | {}/{} tasks ({:.1}%) |\n",
+        metrics.completed_tasks,
+        metrics.total_tasks,
+        metrics.completion_rate * 100.0
+    ));
+    out.push_str(&format!("| Estimated | {:.1}h |\n", metrics.estimated_hours));
+    out.push_str(&format!("| Actual | {:.1}h |\n", metrics.actual_hours));
+    out.push_str(&format!("| Remaining | {:.1}h |\n", metrics.hours_remaining));
+    out.push_str(&format!("| Blocked tasks | {} |\n", metrics.blocked_tasks));
+    out.push_str(&format!("| Active blockers | {} |\n\n", metrics.active_blockers));
+}
+
+fn push_feature_section(
+    out: &mut String,
+    feature: &Feature,
+    tasks: &[Task],
+    labels_of: &dyn Fn(&Task) -> String,
+) {
+    let group: Vec<&Task> = tasks.iter().filter(|t| t.feature_id == feature.id).collect();
+    let done = group.iter().filter(|t| t.status == TaskStatus::Done).count();
+    out.push_str(&format!(
+        "## {} (`{}`) - {}/{} done\n\n",
+        feature.name,
+        feature.id,
+        done,
+        group.len()
+    ));
+    let rows = group
+        .iter()
+        .map(|t| vec![status_heading(t.status).to_string(), labels_of(t)]);
+    push_task_table(out, &group, &["Status", "Labels"], rows);
+}
+
+/// Write a task table with the common columns plus `extra` ones
+fn push_task_table(
+    out: &mut String,
+    tasks: &[&Task],
+    extra: &[&str],
+    extra_cells: impl Iterator<Item = Vec<String>>,
+) {
+    if tasks.is_empty() {
+        out.push_str("_No tasks._\n\n");
+        return;
+    }
+
+    let mut header = vec!["ID", "Title", "Priority", "Agent", "Estimate"];
+    header.extend_from_slice(extra);
+    out.push_str(&format!("| {} |\n", header.join(" | ")));
+    out.push_str(&format!("|{}\n", "---|".repeat(header.len())));
+
+    for (task, extra) in tasks.iter().zip(extra_cells) {
+        let mut row = vec![
+            task.id.clone(),
+            cell(&task.title),
+            task.priority.to_string(),
+            task.assigned_agent.clone().unwrap_or_else(|| "-".to_string()),
+            hours(task.estimated_hours),
+        ];
+        row.extend(extra);
+        out.push_str(&format!("| {} |\n", row.join(" | ")));
+    }
+    out.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CreateBlockerRequest, CreateFeatureRequest, TaskBuilder};
+    use crate::operations::{blockers, features, labels, tasks};
+    use crate::state_machine::BlockerType;
+
+    fn setup_test_db() -> Database {
+        let db = Database::in_memory().unwrap();
+
+        features::create_feature(
+            &db,
+            CreateFeatureRequest {
+                name: "Test Feature".to_string(),
+                description: None,
+                color: None,
+            },
+        )
+        .unwrap();
+
+        for title in ["Parse | split", "Validate"] {
+            let request = TaskBuilder::new()
+                .feature_id("test-feature")
+                .title(title)
+                .estimated_hours(2.0)
+                .build()
+                .unwrap();
+            tasks::create_task(&db, request).unwrap();
+        }
+        tasks::update_task_status(&db, "T-test-feature-002", TaskStatus::InProgress, "test", false)
+            .unwrap();
+        labels::add_task_label(&db, "T-test-feature-001", "bug", "test").unwrap();
+        blockers::add_blocker(
+            &db,
+            CreateBlockerRequest {
+                task_id: "T-test-feature-002".to_string(),
+                blocker_type: BlockerType::Technical,
+                description: "Waiting on schema".to_string(),
+                blocking_task_id: None,
+            },
+        )
+        .unwrap();
+
+        db
+    }
+
+    #[test]
+    fn test_markdown_report_by_status() {
+        let db = setup_test_db();
+        let md = markdown_report(&db, Some("test-feature"), MarkdownGrouping::Status).unwrap();
+
+        assert!(md.starts_with("# Kanban Board: Test Feature\n"));
+        assert!(md.contains("| Progress | 0/2 tasks (0.0%) |"));
+        assert!(md.contains("## To Do (1)"));
+        assert!(md.contains(
+            "| T-test-feature-001 | Parse \\| split | 100 | - | 2.0h | Test Feature | bug |"
+        ));
+        // The blocker moved the in-progress task to blocked
+        assert!(md.contains("## Blocked (1)"));
+        assert!(md.contains("## Done (0)\n\n_No tasks._"));
+        assert!(md.contains("## Blockers (1)"));
+        assert!(md.contains("Waiting on schema"));
+    }
+
+    #[test]
+    fn test_markdown_report_by_feature() {
+        let db = setup_test_db();
+        let md = markdown_report(&db, None, MarkdownGrouping::Feature).unwrap();
+
+        assert!(md.starts_with("# Kanban Board: All active features\n"));
+        assert!(md.contains("## Test Feature (`test-feature`) - 0/2 done"));
+        assert!(md.contains("| T-test-feature-002 | Validate | 100 | - | 2.0h | Blocked | - |"));
+        assert!(markdown_report(&db, Some("missing"), MarkdownGrouping::Status).is_err());
+        assert!(MarkdownGrouping::from_name("agent").is_err());
+    }
+}
@@ -151,7 +151,8 @@ pub fn get_feature_summary(db: &Database, feature_id: &str) -> Result<FeatureSum
             SUM(CASE WHEN status = 'blocked' THEN 1 ELSE 0 END) as blocked,
             SUM(CASE WHEN status = 'in-qa' THEN 1 ELSE 0 END) as in_qa,
             SUM(CASE WHEN status = 'done' THEN 1 ELSE 0 END) as done
-        FROM tasks WHERE feature_id = ?
+        FROM tasks
+        WHERE feature_id = ? AND id NOT IN (SELECT task_id FROM archived_tasks)
         "#,
         params![feature_id],
         |row| {
@@ -0,0 +1,311 @@
+//! Iteration (sprint) operations
+
+use chrono::{NaiveDate, Utc};
+use rusqlite::{params, OptionalExtension, Row};
+
+use crate::db::Database;
+use crate::models::{Iteration, IterationTask};
+
+use super::features::generate_feature_id;
+use super::tasks::{get_task, record_history};
+use super::{OperationError, Result};
+
+/// Parse an iteration from a database row
+fn iteration_from_row(row: &Row) -> rusqlite::Result<Iteration> {
+    Ok(Iteration {
+        id: row.get("id")?,
+        name: row.get("name")?,
+        start_date: parse_date(&row.get::<_, String>("start_date")?),
+        end_date: parse_date(&row.get::<_, String>("end_date")?),
+        created_at: parse_datetime(row.get::<_, String>("created_at")?),
+    })
+}
+
+fn parse_date(s: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap_or_else(|_| Utc::now().date_naive())
+}
+
+fn parse_datetime(s: String) -> chrono::DateTime<Utc> {
+    chrono::DateTime::parse_from_rfc3339(&s)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|_| {
+            chrono::NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S")
+                .map(|dt| dt.and_utc())
+                .unwrap_or_else(|_| Utc::now())
+        })
+}
+
+/// Create an iteration
+///
+/// The ID is a slug of the name ("Sprint 12" becomes "sprint-12"). Dates
+/// are inclusive, so a one-day iteration starts and ends on the same day.
+pub fn create_iteration(
+    db: &Database,
+    name: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<Iteration> {
+    let id = generate_feature_id(name);
+    if id.is_empty() {
+        return Err(OperationError::Validation(
+            "Iteration name cannot be empty".to_string(),
+        ));
+    }
+    if end_date < start_date {
+        return Err(OperationError::Validation(format!(
+            "Iteration cannot end ({}) before it starts ({})",
+            end_date, start_date
+        )));
+    }
+    if get_iteration(db, &id).is_ok() {
+        return Err(OperationError::Validation(format!(
+            "Iteration '{}' already exists",
+            id
+        )));
+    }
+
+    db.conn().execute(
+        "INSERT INTO iterations (id, name, start_date, end_date, created_at) VALUES (?, ?, ?, ?, ?)",
+        params![
+            id,
+            name.trim(),
+            start_date.to_string(),
+            end_date.to_string(),
+            Utc::now().to_rfc3339()
+        ],
+    )?;
+
+    get_iteration(db, &id)
+}
+
+/// Get an iteration by ID
+pub fn get_iteration(db: &Database, iteration_id: &str) -> Result<Iteration> {
+    db.conn()
+        .query_row(
+            "SELECT * FROM iterations WHERE id = ?",
+            params![iteration_id],
+            iteration_from_row,
+        )
+        .map_err(|e| match e {
+            rusqlite::Error::QueryReturnedNoRows => {
+                OperationError::NotFound(format!("Iteration not found: {}", iteration_id))
+            }
+            e => OperationError::Database(e),
+        })
+}
+
+/// List iterations from earliest to latest start
+pub fn list_iterations(db: &Database) -> Result<Vec<Iteration>> {
+    let mut stmt = db
+        .conn()
+        .prepare("SELECT * FROM iterations ORDER BY start_date, id")?;
+    let iterations = stmt
+        .query_map([], iteration_from_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(iterations)
+}
+
+/// Get the iteration running on `date`, preferring the latest to start
+pub fn current_iteration(db: &Database, date: NaiveDate) -> Result<Option<Iteration>> {
+    let iteration = db
+        .conn()
+        .query_row(
+            r#"
+            SELECT * FROM iterations
+            WHERE start_date <= ?1 AND end_date >= ?1
+            ORDER BY start_date DESC
+            LIMIT 1
+            "#,
+            params![date.to_string()],
+            iteration_from_row,
+        )
+        .optional()?;
+    Ok(iteration)
+}
+
+/// Delete an iteration; its tasks stay on the board
+pub fn delete_iteration(db: &Database, iteration_id: &str) -> Result<()> {
+    get_iteration(db, iteration_id)?;
+
+    let tx = db.conn().unchecked_transaction()?;
+    tx.execute(
+        "DELETE FROM iteration_tasks WHERE iteration_id = ?",
+        params![iteration_id],
+    )?;
+    tx.execute("DELETE FROM iterations WHERE id = ?", params![iteration_id])?;
+    tx.commit()?;
+
+    Ok(())
+}
+
+/// Commit a task to an iteration, optionally with story points
+///
+/// Adding a task that is already committed updates its points, if given.
+/// A task carried over into a later iteration stays committed to the earlier
+/// one, which then reports it as not completed.
+pub fn add_iteration_task(
+    db: &Database,
+    iteration_id: &str,
+    task_id: &str,
+    points: Option<i64>,
+    changed_by: &str,
+) -> Result<()> {
+    get_iteration(db, iteration_id)?;
+    get_task(db, task_id)?;
+    if points.is_some_and(|p| p < 0) {
+        return Err(OperationError::Validation(
+            "Points cannot be negative".to_string(),
+        ));
+    }
+
+    let added = db.conn().execute(
+        "INSERT OR IGNORE INTO iteration_tasks (iteration_id, task_id, points, added_at) VALUES (?, ?, ?, ?)",
+        params![iteration_id, task_id, points, Utc::now().to_rfc3339()],
+    )? > 0;
+
+    if added {
+        record_history(db, task_id, "iteration", None, Some(iteration_id), changed_by)?;
+    } else {
+        db.conn().execute(
+            "UPDATE iteration_tasks SET points = COALESCE(?, points) WHERE iteration_id = ? AND task_id = ?",
+            params![points, iteration_id, task_id],
+        )?;
+    }
+    Ok(())
+}
+
+/// Take a task out of an iteration
+pub fn remove_iteration_task(
+    db: &Database,
+    iteration_id: &str,
+    task_id: &str,
+    changed_by: &str,
+) -> Result<()> {
+    let removed = db.conn().execute(
+        "DELETE FROM iteration_tasks WHERE iteration_id = ? AND task_id = ?",
+        params![iteration_id, task_id],
+    )?;
+    if removed == 0 {
+        return Err(OperationError::NotFound(format!(
+            "Task {} is not in iteration {}",
+            task_id, iteration_id
+        )));
+    }
+
+    record_history(db, task_id, "iteration", Some(iteration_id), None, changed_by)?;
+    Ok(())
+}
+
+/// Get the unarchived tasks committed to an iteration, by priority
+pub fn get_iteration_tasks(db: &Database, iteration_id: &str) -> Result<Vec<IterationTask>> {
+    get_iteration(db, iteration_id)?;
+
+    let mut stmt = db.conn().prepare(
+        r#"
+        SELECT it.task_id, it.points FROM iteration_tasks it
+        JOIN tasks t ON it.task_id = t.id
+        WHERE it.iteration_id = ?
+          AND t.id NOT IN (SELECT task_id FROM archived_tasks)
+        ORDER BY t.priority, t.id
+        "#,
+    )?;
+    let rows = stmt
+        .query_map(params![iteration_id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, Option<i64>>(1)?))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    rows.into_iter()
+        .map(|(task_id, points)| {
+            Ok(IterationTask {
+                task: get_task(db, &task_id)?,
+                points,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CreateFeatureRequest, TaskBuilder};
+    use crate::operations::{features, tasks};
+
+    fn setup_test_db() -> Database {
+        let db = Database::in_memory().unwrap();
+
+        features::create_feature(
+            &db,
+            CreateFeatureRequest {
+                name: "Test Feature".to_string(),
+                description: None,
+                color: None,
+            },
+        )
+        .unwrap();
+
+        let request = TaskBuilder::new()
+            .feature_id("test-feature")
+            .title("Test task")
+            .build()
+            .unwrap();
+        tasks::create_task(&db, request).unwrap();
+
+        db
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_create_iteration() {
+        let db = setup_test_db();
+
+        let sprint = create_iteration(&db, "Sprint 1", date("2026-03-02"), date("2026-03-13"))
+            .unwrap();
+        assert_eq!(sprint.id, "sprint-1");
+        assert_eq!(sprint.end_date, date("2026-03-13"));
+
+        assert!(create_iteration(&db, "sprint 1", date("2026-03-16"), date("2026-03-27")).is_err());
+        assert!(create_iteration(&db, "Sprint 2", date("2026-03-16"), date("2026-03-15")).is_err());
+        create_iteration(&db, "Sprint 2", date("2026-03-16"), date("2026-03-27")).unwrap();
+
+        let found = current_iteration(&db, date("2026-03-20")).unwrap().unwrap();
+        assert_eq!(found.id, "sprint-2");
+        assert!(current_iteration(&db, date("2026-04-01")).unwrap().is_none());
+        assert_eq!(list_iterations(&db).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_iteration_tasks() {
+        let db = setup_test_db();
+        create_iteration(&db, "Sprint 1", date("2026-03-02"), date("2026-03-13")).unwrap();
+        let task_id = "T-test-feature-001";
+
+        add_iteration_task(&db, "sprint-1", task_id, Some(3), "test").unwrap();
+        // Adding again updates the points without a second history entry
+        add_iteration_task(&db, "sprint-1", task_id, Some(5), "test").unwrap();
+        let committed = get_iteration_tasks(&db, "sprint-1").unwrap();
+        assert_eq!(committed.len(), 1);
+        assert_eq!(committed[0].points, Some(5));
+        // Adding again without points keeps them
+        add_iteration_task(&db, "sprint-1", task_id, None, "test").unwrap();
+        assert_eq!(get_iteration_tasks(&db, "sprint-1").unwrap()[0].points, Some(5));
+
+        let history = tasks::get_task_history(&db, task_id).unwrap();
+        assert_eq!(history.iter().filter(|h| h.field_changed == "iteration").count(), 1);
+
+        assert!(add_iteration_task(&db, "sprint-1", task_id, Some(-1), "test").is_err());
+        assert!(add_iteration_task(&db, "missing", task_id, None, "test").is_err());
+
+        remove_iteration_task(&db, "sprint-1", task_id, "test").unwrap();
+        assert!(matches!(
+            remove_iteration_task(&db, "sprint-1", task_id, "test"),
+            Err(OperationError::NotFound(_))
+        ));
+
+        delete_iteration(&db, "sprint-1").unwrap();
+        assert!(get_iteration(&db, "sprint-1").is_err());
+    }
+}
@@ -0,0 +1,223 @@
+//! Label operations
+
+use std::collections::HashMap;
+
+use rusqlite::params;
+
+use crate::db::Database;
+use crate::models::Label;
+
+use super::tasks::{get_task, record_history};
+use super::{OperationError, Result};
+
+/// Normalize a label name: lowercase, without a leading '#'
+///
+/// Labels are single words (e.g. "bug", "tech-debt", "p0") so they can be
+/// typed as search tokens and shown as compact chips.
+pub fn normalize_label(label: &str) -> Result<String> {
+    let name = label.trim().trim_start_matches('#').to_lowercase();
+    if name.is_empty() {
+        return Err(OperationError::Validation(
+            "Label cannot be empty".to_string(),
+        ));
+    }
+    if name.chars().any(|c| c.is_whitespace() || c == ',') {
+        return Err(OperationError::Validation(format!(
+            "Label '{}' cannot contain spaces or commas",
+            label.trim()
+        )));
+    }
+    Ok(name)
+}
+
+/// Attach a label to a task, creating the label if needed
+///
+/// Returns false if the task already had the label.
+pub fn add_task_label(
+    db: &Database,
+    task_id: &str,
+    label: &str,
+    changed_by: &str,
+) -> Result<bool> {
+    get_task(db, task_id)?;
+    let name = normalize_label(label)?;
+
+    db.conn().execute(
+        "INSERT OR IGNORE INTO labels (name) VALUES (?)",
+        params![name],
+    )?;
+    let added = db.conn().execute(
+        "INSERT OR IGNORE INTO task_labels (task_id, label) VALUES (?, ?)",
+        params![task_id, name],
+    )? > 0;
+
+    if added {
+        record_history(db, task_id, "label", None, Some(&name), changed_by)?;
+    }
+    Ok(added)
+}
+
+/// Remove a label from a task
+pub fn remove_task_label(
+    db: &Database,
+    task_id: &str,
+    label: &str,
+    changed_by: &str,
+) -> Result<()> {
+    get_task(db, task_id)?;
+    let name = normalize_label(label)?;
+
+    let removed = db.conn().execute(
+        "DELETE FROM task_labels WHERE task_id = ? AND label = ?",
+        params![task_id, name],
+    )?;
+    if removed == 0 {
+        return Err(OperationError::NotFound(format!(
+            "Task {} does not have label '{}'",
+            task_id, name
+        )));
+    }
+
+    record_history(db, task_id, "label", Some(&name), None, changed_by)?;
+    Ok(())
+}
+
+/// Get the labels on a task, sorted by name
+pub fn get_task_labels(db: &Database, task_id: &str) -> Result<Vec<String>> {
+    let mut stmt = db
+        .conn()
+        .prepare("SELECT label FROM task_labels WHERE task_id = ? ORDER BY label")?;
+    let labels = stmt
+        .query_map(params![task_id], |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<String>>>()?;
+    Ok(labels)
+}
+
+/// Get the labels of every task in a feature, keyed by task ID
+pub fn get_feature_labels(
+    db: &Database,
+    feature_id: &str,
+) -> Result<HashMap<String, Vec<String>>> {
+    let mut stmt = db.conn().prepare(
+        r#"
+        SELECT tl.task_id, tl.label FROM task_labels tl
+        JOIN tasks t ON tl.task_id = t.id
+        WHERE t.feature_id = ?
+        ORDER BY tl.label
+        "#,
+    )?;
+    let rows = stmt.query_map(params![feature_id], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+    })?;
+
+    let mut labels: HashMap<String, Vec<String>> = HashMap::new();
+    for row in rows {
+        let (task_id, label) = row?;
+        labels.entry(task_id).or_default().push(label);
+    }
+    Ok(labels)
+}
+
+/// List all labels with the number of unarchived tasks carrying each
+pub fn list_labels(db: &Database) -> Result<Vec<Label>> {
+    let mut stmt = db.conn().prepare(
+        r#"
+        SELECT l.name, COUNT(tl.task_id) FROM labels l
+        LEFT JOIN task_labels tl ON tl.label = l.name
+          AND tl.task_id NOT IN (SELECT task_id FROM archived_tasks)
+        GROUP BY l.name
+        ORDER BY l.name
+        "#,
+    )?;
+    let labels = stmt
+        .query_map([], |row| {
+            Ok(Label {
+                name: row.get(0)?,
+                task_count: row.get(1)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(labels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CreateFeatureRequest, TaskBuilder};
+    use crate::operations::{features, tasks};
+
+    fn setup_test_db() -> (Database, String) {
+        let db = Database::in_memory().unwrap();
+
+        features::create_feature(
+            &db,
+            CreateFeatureRequest {
+                name: "Test Feature".to_string(),
+                description: None,
+                color: None,
+            },
+        )
+        .unwrap();
+
+        let request = TaskBuilder::new()
+            .feature_id("test-feature")
+            .title("Test task")
+            .build()
+            .unwrap();
+        let task = tasks::create_task(&db, request).unwrap();
+
+        (db, task.id)
+    }
+
+    #[test]
+    fn test_normalize_label() {
+        assert_eq!(normalize_label(" #Tech-Debt ").unwrap(), "tech-debt");
+        assert!(normalize_label("#").is_err());
+        assert!(normalize_label("two words").is_err());
+    }
+
+    #[test]
+    fn test_task_labels() {
+        let (db, task_id) = setup_test_db();
+
+        assert!(add_task_label(&db, &task_id, "Bug", "dev").unwrap());
+        assert!(!add_task_label(&db, &task_id, "bug", "dev").unwrap());
+        add_task_label(&db, &task_id, "p0", "dev").unwrap();
+        assert_eq!(get_task_labels(&db, &task_id).unwrap(), vec!["bug", "p0"]);
+
+        let by_task = get_feature_labels(&db, "test-feature").unwrap();
+        assert_eq!(by_task[&task_id], vec!["bug", "p0"]);
+
+        // Filtering requires every requested label
+        let other = TaskBuilder::new()
+            .feature_id("test-feature")
+            .title("Other task")
+            .build()
+            .unwrap();
+        let other = tasks::create_task(&db, other).unwrap();
+        add_task_label(&db, &other.id, "bug", "dev").unwrap();
+        let bug = vec!["bug".to_string()];
+        let bug_p0 = vec!["bug".to_string(), "p0".to_string()];
+        assert_eq!(tasks::list_tasks(&db, None, None, None, &bug).unwrap().len(), 2);
+        let both = tasks::list_tasks(&db, None, None, None, &bug_p0).unwrap();
+        assert_eq!(both.len(), 1);
+        assert_eq!(both[0].id, task_id);
+
+        remove_task_label(&db, &task_id, "p0", "dev").unwrap();
+        assert!(matches!(
+            remove_task_label(&db, &task_id, "p0", "dev"),
+            Err(OperationError::NotFound(_))
+        ));
+
+        // The label itself stays around with no tasks
+        let labels = list_labels(&db).unwrap();
+        let counts: Vec<(&str, i64)> = labels
+            .iter()
+            .map(|l| (l.name.as_str(), l.task_count))
+            .collect();
+        assert_eq!(counts, vec![("bug", 2), ("p0", 0)]);
+
+        let history = tasks::get_task_history(&db, &task_id).unwrap();
+        assert_eq!(history.iter().filter(|h| h.field_changed == "label").count(), 3);
+    }
+}
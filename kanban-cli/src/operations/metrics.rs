@@ -1,14 +1,15 @@
 //! Feature metrics and agent workload calculations
 
-use chrono::Utc;
+use chrono::{NaiveDate, Utc};
 use rusqlite::params;
 use serde::{Deserialize, Serialize};
 
 use crate::db::Database;
-use crate::models::AgentWorkload;
+use crate::models::{AgentWorkload, Iteration, IterationTask};
 use crate::state_machine::{AgentStatus, AgentType};
 
 use super::features::get_feature;
+use super::iterations::{get_iteration, get_iteration_tasks, list_iterations};
 use super::{OperationError, Result};
 
 /// Feature metrics
@@ -43,7 +44,8 @@ pub fn get_feature_metrics(db: &Database, feature_id: &str) -> Result<FeatureMet
             COUNT(*) as total,
             SUM(CASE WHEN status = 'done' THEN 1 ELSE 0 END) as done,
             SUM(CASE WHEN status = 'blocked' THEN 1 ELSE 0 END) as blocked
-        FROM tasks WHERE feature_id = ?
+        FROM tasks
+        WHERE feature_id = ? AND id NOT IN (SELECT task_id FROM archived_tasks)
         "#,
         params![feature_id],
         |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
@@ -55,7 +57,8 @@ pub fn get_feature_metrics(db: &Database, feature_id: &str) -> Result<FeatureMet
         SELECT
             COALESCE(SUM(estimated_hours), 0) as estimated,
             COALESCE(SUM(actual_hours), 0) as actual
-        FROM tasks WHERE feature_id = ?
+        FROM tasks
+        WHERE feature_id = ? AND id NOT IN (SELECT task_id FROM archived_tasks)
         "#,
         params![feature_id],
         |row| Ok((row.get(0)?, row.get(1)?)),
@@ -67,6 +70,7 @@ pub fn get_feature_metrics(db: &Database, feature_id: &str) -> Result<FeatureMet
         SELECT COUNT(*) FROM blockers b
         JOIN tasks t ON b.task_id = t.id
         WHERE t.feature_id = ? AND b.status = 'active'
+          AND t.id NOT IN (SELECT task_id FROM archived_tasks)
         "#,
         params![feature_id],
         |row| row.get(0),
@@ -83,7 +87,9 @@ pub fn get_feature_metrics(db: &Database, feature_id: &str) -> Result<FeatureMet
     let hours_remaining: f64 = db.conn().query_row(
         r#"
         SELECT COALESCE(SUM(estimated_hours), 0)
-        FROM tasks WHERE feature_id = ? AND status != 'done'
+        FROM tasks
+        WHERE feature_id = ? AND status != 'done'
+          AND id NOT IN (SELECT task_id FROM archived_tasks)
         "#,
         params![feature_id],
         |row| row.get(0),
@@ -102,6 +108,154 @@ pub fn get_feature_metrics(db: &Database, feature_id: &str) -> Result<FeatureMet
     })
 }
 
+/// Remaining work at the end of one day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BurndownPoint {
+    pub date: NaiveDate,
+    pub remaining_tasks: i64,
+    pub remaining_hours: f64,
+}
+
+/// Calculate a daily burndown for a feature
+///
+/// Scope grows when tasks are created and shrinks when task history records
+/// a move to done (growing again if a task is reverted out of done), so each
+/// point is the work left open at the end of that day.
+pub fn get_feature_burndown(db: &Database, feature_id: &str) -> Result<Vec<BurndownPoint>> {
+    get_feature(db, feature_id)?;
+
+    // (date, task delta, hours delta)
+    let mut events: Vec<(NaiveDate, i64, f64)> = Vec::new();
+
+    let mut stmt = db.conn().prepare(
+        "SELECT created_at, estimated_hours FROM tasks
+         WHERE feature_id = ? AND id NOT IN (SELECT task_id FROM archived_tasks)",
+    )?;
+    let created = stmt
+        .query_map(params![feature_id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, Option<f64>>(1)?))
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    for (at, hours) in created {
+        events.push((parse_date(&at), 1, hours.unwrap_or(0.0)));
+    }
+
+    let mut stmt = db.conn().prepare(
+        r#"
+        SELECT h.changed_at, t.estimated_hours, h.new_value = 'done'
+        FROM task_history h
+        JOIN tasks t ON h.task_id = t.id
+        WHERE t.feature_id = ? AND h.field_changed = 'status'
+          AND (h.new_value = 'done' OR h.old_value = 'done')
+          AND t.id NOT IN (SELECT task_id FROM archived_tasks)
+        "#,
+    )?;
+    let transitions = stmt
+        .query_map(params![feature_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, Option<f64>>(1)?,
+                row.get::<_, bool>(2)?,
+            ))
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    for (at, hours, completed) in transitions {
+        // A task reverted out of done goes back into the remaining work
+        let sign = if completed { -1 } else { 1 };
+        events.push((parse_date(&at), sign, sign as f64 * hours.unwrap_or(0.0)));
+    }
+
+    let Some(start) = events.iter().map(|(date, _, _)| *date).min() else {
+        return Ok(Vec::new());
+    };
+    let today = Utc::now().date_naive();
+    let end = events
+        .iter()
+        .map(|(date, _, _)| *date)
+        .max()
+        .unwrap_or(start)
+        .max(today);
+
+    let mut points = Vec::new();
+    let (mut tasks, mut hours) = (0i64, 0.0f64);
+    for date in start.iter_days().take_while(|d| *d <= end) {
+        for (_, task_delta, hours_delta) in events.iter().filter(|(d, _, _)| *d == date) {
+            tasks += task_delta;
+            hours += hours_delta;
+        }
+        points.push(BurndownPoint {
+            date,
+            remaining_tasks: tasks,
+            remaining_hours: hours.max(0.0),
+        });
+    }
+
+    Ok(points)
+}
+
+/// Parse the date part of a stored timestamp
+fn parse_date(s: &str) -> NaiveDate {
+    chrono::DateTime::parse_from_rfc3339(s)
+        .map(|dt| dt.with_timezone(&Utc).date_naive())
+        .or_else(|_| NaiveDate::parse_from_str(s.get(..10).unwrap_or(s), "%Y-%m-%d"))
+        .unwrap_or_else(|_| Utc::now().date_naive())
+}
+
+/// Progress and effort for one label
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LabelMetrics {
+    pub label: String,
+    pub total_tasks: i64,
+    pub completed_tasks: i64,
+    pub blocked_tasks: i64,
+    pub estimated_hours: f64,
+    pub actual_hours: f64,
+    pub hours_remaining: f64,
+}
+
+/// Calculate metrics per label, optionally limited to one feature
+///
+/// A task with several labels counts toward each of them. Archived tasks
+/// are left out.
+pub fn get_label_metrics(db: &Database, feature_id: Option<&str>) -> Result<Vec<LabelMetrics>> {
+    if let Some(fid) = feature_id {
+        get_feature(db, fid)?;
+    }
+
+    let mut stmt = db.conn().prepare(
+        r#"
+        SELECT
+            tl.label,
+            COUNT(*) as total,
+            SUM(CASE WHEN t.status = 'done' THEN 1 ELSE 0 END) as done,
+            SUM(CASE WHEN t.status = 'blocked' THEN 1 ELSE 0 END) as blocked,
+            COALESCE(SUM(t.estimated_hours), 0) as estimated,
+            COALESCE(SUM(t.actual_hours), 0) as actual,
+            COALESCE(SUM(CASE WHEN t.status != 'done' THEN t.estimated_hours END), 0) as remaining
+        FROM task_labels tl
+        JOIN tasks t ON tl.task_id = t.id
+        WHERE (?1 IS NULL OR t.feature_id = ?1)
+          AND t.id NOT IN (SELECT task_id FROM archived_tasks)
+        GROUP BY tl.label
+        ORDER BY tl.label
+        "#,
+    )?;
+    let metrics = stmt
+        .query_map(params![feature_id], |row| {
+            Ok(LabelMetrics {
+                label: row.get(0)?,
+                total_tasks: row.get(1)?,
+                completed_tasks: row.get(2)?,
+                blocked_tasks: row.get(3)?,
+                estimated_hours: row.get(4)?,
+                actual_hours: row.get(5)?,
+                hours_remaining: row.get(6)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(metrics)
+}
+
 /// Get overall metrics across all active features
 pub fn get_overall_metrics(db: &Database) -> Result<FeatureMetrics> {
     // Task counts
@@ -109,11 +263,11 @@ pub fn get_overall_metrics(db: &Database) -> Result<FeatureMetrics> {
         r#"
         SELECT
             COUNT(*) as total,
-            SUM(CASE WHEN status = 'done' THEN 1 ELSE 0 END) as done,
-            SUM(CASE WHEN status = 'blocked' THEN 1 ELSE 0 END) as blocked
+            SUM(CASE WHEN t.status = 'done' THEN 1 ELSE 0 END) as done,
+            SUM(CASE WHEN t.status = 'blocked' THEN 1 ELSE 0 END) as blocked
         FROM tasks t
         JOIN features f ON t.feature_id = f.id
-        WHERE f.status = 'active'
+        WHERE f.status = 'active' AND t.id NOT IN (SELECT task_id FROM archived_tasks)
         "#,
         [],
         |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
@@ -123,11 +277,11 @@ pub fn get_overall_metrics(db: &Database) -> Result<FeatureMetrics> {
     let (estimated, actual): (f64, f64) = db.conn().query_row(
         r#"
         SELECT
-            COALESCE(SUM(estimated_hours), 0) as estimated,
-            COALESCE(SUM(actual_hours), 0) as actual
+            COALESCE(SUM(t.estimated_hours), 0) as estimated,
+            COALESCE(SUM(t.actual_hours), 0) as actual
         FROM tasks t
         JOIN features f ON t.feature_id = f.id
-        WHERE f.status = 'active'
+        WHERE f.status = 'active' AND t.id NOT IN (SELECT task_id FROM archived_tasks)
         "#,
         [],
         |row| Ok((row.get(0)?, row.get(1)?)),
@@ -153,10 +307,11 @@ pub fn get_overall_metrics(db: &Database) -> Result<FeatureMetrics> {
     // Hours remaining
     let hours_remaining: f64 = db.conn().query_row(
         r#"
-        SELECT COALESCE(SUM(estimated_hours), 0)
+        SELECT COALESCE(SUM(t.estimated_hours), 0)
         FROM tasks t
         JOIN features f ON t.feature_id = f.id
         WHERE f.status = 'active' AND t.status != 'done'
+          AND t.id NOT IN (SELECT task_id FROM archived_tasks)
         "#,
         [],
         |row| row.get(0),
@@ -175,6 +330,105 @@ pub fn get_overall_metrics(db: &Database) -> Result<FeatureMetrics> {
     })
 }
 
+/// Committed and completed work for one iteration
+///
+/// Hours are estimates, so committed and completed hours compare like for
+/// like; `actual_hours` is the time logged on the committed tasks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IterationSummary {
+    pub iteration: Iteration,
+    pub committed_tasks: i64,
+    pub completed_tasks: i64,
+    pub committed_points: i64,
+    pub completed_points: i64,
+    pub committed_hours: f64,
+    pub completed_hours: f64,
+    pub actual_hours: f64,
+}
+
+/// Average completed work over recent finished iterations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Velocity {
+    pub points: f64,
+    pub hours: f64,
+    /// The iterations averaged, earliest first
+    pub history: Vec<IterationSummary>,
+}
+
+/// Sprint report: one iteration's summary, its tasks, and rolling velocity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SprintReport {
+    pub summary: IterationSummary,
+    pub tasks: Vec<IterationTask>,
+    /// None until an iteration up to this one has finished
+    pub velocity: Option<Velocity>,
+}
+
+fn summarize_iteration(iteration: Iteration, tasks: &[IterationTask]) -> IterationSummary {
+    let completed: Vec<&IterationTask> =
+        tasks.iter().filter(|t| t.completed_in(&iteration)).collect();
+    let points = |t: &IterationTask| t.points.unwrap_or(0);
+    let hours = |t: &IterationTask| t.task.estimated_hours.unwrap_or(0.0);
+
+    IterationSummary {
+        committed_tasks: tasks.len() as i64,
+        completed_tasks: completed.len() as i64,
+        committed_points: tasks.iter().map(points).sum(),
+        completed_points: completed.iter().map(|t| points(t)).sum(),
+        // fold from 0.0 because an empty f64 sum is -0.0
+        committed_hours: tasks.iter().map(hours).fold(0.0, |a, h| a + h),
+        completed_hours: completed.iter().map(|t| hours(t)).fold(0.0, |a, h| a + h),
+        actual_hours: tasks
+            .iter()
+            .filter_map(|t| t.task.actual_hours)
+            .fold(0.0, |a, h| a + h),
+        iteration,
+    }
+}
+
+/// Summarize an iteration
+pub fn get_iteration_summary(db: &Database, iteration_id: &str) -> Result<IterationSummary> {
+    let iteration = get_iteration(db, iteration_id)?;
+    let tasks = get_iteration_tasks(db, iteration_id)?;
+    Ok(summarize_iteration(iteration, &tasks))
+}
+
+/// Build a sprint report
+///
+/// Velocity averages completed points and hours over the last `window`
+/// iterations that have ended, up to and including this one, so the report
+/// for a running sprint shows the throughput to plan it against.
+pub fn get_sprint_report(db: &Database, iteration_id: &str, window: usize) -> Result<SprintReport> {
+    let iteration = get_iteration(db, iteration_id)?;
+    let tasks = get_iteration_tasks(db, iteration_id)?;
+    let today = Utc::now().date_naive();
+
+    let finished: Vec<Iteration> = list_iterations(db)?
+        .into_iter()
+        .filter(|i| i.start_date <= iteration.start_date && i.end_date < today)
+        .collect();
+    let recent = &finished[finished.len().saturating_sub(window.max(1))..];
+    let history = recent
+        .iter()
+        .map(|i| get_iteration_summary(db, &i.id))
+        .collect::<Result<Vec<_>>>()?;
+
+    let velocity = (!history.is_empty()).then(|| {
+        let n = history.len() as f64;
+        Velocity {
+            points: history.iter().map(|s| s.completed_points as f64).sum::<f64>() / n,
+            hours: history.iter().map(|s| s.completed_hours).sum::<f64>() / n,
+            history,
+        }
+    });
+
+    Ok(SprintReport {
+        summary: summarize_iteration(iteration, &tasks),
+        tasks,
+        velocity,
+    })
+}
+
 /// Get agent workload
 pub fn get_agent_workload(db: &Database, agent_id: &str) -> Result<AgentWorkload> {
     // Get agent info
@@ -211,9 +465,13 @@ pub fn get_agent_workload(db: &Database, agent_id: &str) -> Result<AgentWorkload
             _ => OperationError::Database(e),
         })?;
 
-    // Get current tasks
+    // Get current tasks, leaving out archived ones
     let mut stmt = db.conn().prepare(
-        "SELECT id FROM tasks WHERE assigned_agent = ? AND status IN ('in-progress', 'blocked')",
+        r#"
+        SELECT id FROM tasks
+        WHERE assigned_agent = ? AND status IN ('in-progress', 'blocked')
+          AND id NOT IN (SELECT task_id FROM archived_tasks)
+        "#,
     )?;
     let task_ids: Vec<String> = stmt
         .query_map(params![agent_id], |row| row.get(0))?
@@ -301,7 +559,7 @@ pub fn list_agents(db: &Database) -> Result<Vec<AgentWorkload>> {
 mod tests {
     use super::*;
     use crate::models::{CreateFeatureRequest, TaskBuilder};
-    use crate::operations::{features, tasks};
+    use crate::operations::{features, iterations, labels, tasks};
     use crate::state_machine::TaskStatus;
 
     fn setup_test_db() -> Database {
@@ -330,9 +588,10 @@ mod tests {
 
             // Complete some tasks
             if i <= 2 {
-                tasks::update_task_status(&db, &task.id, TaskStatus::InProgress, "test").unwrap();
-                tasks::update_task_status(&db, &task.id, TaskStatus::InQa, "test").unwrap();
-                tasks::update_task_status(&db, &task.id, TaskStatus::Done, "test").unwrap();
+                tasks::update_task_status(&db, &task.id, TaskStatus::InProgress, "test", false)
+                    .unwrap();
+                tasks::update_task_status(&db, &task.id, TaskStatus::InQa, "test", false).unwrap();
+                tasks::update_task_status(&db, &task.id, TaskStatus::Done, "test", false).unwrap();
             }
         }
 
@@ -349,6 +608,115 @@ mod tests {
         assert!((metrics.completion_rate - 0.4).abs() < 0.01);
     }
 
+    #[test]
+    fn test_overall_metrics() {
+        let db = setup_test_db();
+        let metrics = get_overall_metrics(&db).unwrap();
+
+        assert_eq!(metrics.feature_id, "all");
+        assert_eq!(metrics.total_tasks, 5);
+        assert_eq!(metrics.completed_tasks, 2);
+        assert!((metrics.hours_remaining - 12.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_label_metrics() {
+        let db = setup_test_db();
+        labels::add_task_label(&db, "T-test-feature-001", "bug", "test").unwrap();
+        labels::add_task_label(&db, "T-test-feature-003", "bug", "test").unwrap();
+        labels::add_task_label(&db, "T-test-feature-003", "p0", "test").unwrap();
+
+        let metrics = get_label_metrics(&db, Some("test-feature")).unwrap();
+        assert_eq!(metrics.len(), 2);
+        let bug = &metrics[0];
+        assert_eq!(bug.label, "bug");
+        assert_eq!((bug.total_tasks, bug.completed_tasks), (2, 1));
+        assert!((bug.estimated_hours - 8.0).abs() < 0.01);
+        assert!((bug.hours_remaining - 4.0).abs() < 0.01);
+        assert_eq!(metrics[1].total_tasks, 1);
+
+        assert_eq!(get_label_metrics(&db, None).unwrap().len(), 2);
+        assert!(get_label_metrics(&db, Some("missing")).is_err());
+    }
+
+    #[test]
+    fn test_feature_burndown() {
+        let db = setup_test_db();
+        let points = get_feature_burndown(&db, "test-feature").unwrap();
+
+        // Everything happened today: 5 tasks created, 2 completed
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].remaining_tasks, 3);
+        assert!((points[0].remaining_hours - 12.0).abs() < 0.01);
+
+        // Reopening a completed task puts it back into the remaining work
+        let done = tasks::list_tasks(&db, Some("test-feature"), Some(TaskStatus::Done), None, &[])
+            .unwrap();
+        tasks::revert_task_status(&db, &done[0].id, TaskStatus::InQa, "test").unwrap();
+        let points = get_feature_burndown(&db, "test-feature").unwrap();
+        assert_eq!(points[0].remaining_tasks, 4);
+
+        assert!(get_feature_burndown(&db, "missing").is_err());
+    }
+
+    #[test]
+    fn test_sprint_report() {
+        let db = setup_test_db();
+        let date = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        let complete_on = |task_id: &str, at: &str| {
+            db.conn()
+                .execute(
+                    "UPDATE tasks SET completed_at = ? WHERE id = ?",
+                    params![at, task_id],
+                )
+                .unwrap();
+        };
+        complete_on("T-test-feature-001", "2020-01-10T12:00:00+00:00");
+        complete_on("T-test-feature-002", "2020-01-25T12:00:00+00:00");
+
+        iterations::create_iteration(&db, "Sprint 1", date("2020-01-06"), date("2020-01-17"))
+            .unwrap();
+        iterations::create_iteration(&db, "Sprint 2", date("2020-01-20"), date("2020-01-31"))
+            .unwrap();
+        let today = Utc::now().date_naive();
+        let current = iterations::create_iteration(
+            &db,
+            "Sprint 3",
+            today - chrono::Duration::days(2),
+            today + chrono::Duration::days(7),
+        )
+        .unwrap();
+
+        let commit = |sprint: &str, task: &str, points: Option<i64>| {
+            iterations::add_iteration_task(&db, sprint, task, points, "test").unwrap();
+        };
+        commit("sprint-1", "T-test-feature-001", Some(3));
+        commit("sprint-1", "T-test-feature-002", Some(5));
+        // Task 2 finished after sprint 1 ended and was carried over
+        commit("sprint-2", "T-test-feature-002", Some(5));
+        commit(&current.id, "T-test-feature-003", Some(2));
+        commit(&current.id, "T-test-feature-004", None);
+
+        let first = get_sprint_report(&db, "sprint-1", 3).unwrap();
+        assert_eq!((first.summary.committed_points, first.summary.completed_points), (8, 3));
+        assert_eq!((first.summary.committed_tasks, first.summary.completed_tasks), (2, 1));
+        assert!((first.summary.committed_hours - 8.0).abs() < 0.01);
+        assert_eq!(first.velocity.unwrap().history.len(), 1);
+
+        let report = get_sprint_report(&db, &current.id, 3).unwrap();
+        assert_eq!(report.tasks.len(), 2);
+        assert_eq!((report.summary.committed_points, report.summary.completed_points), (2, 0));
+        // The running sprint is not averaged into its own velocity
+        let velocity = report.velocity.unwrap();
+        assert_eq!(velocity.history.len(), 2);
+        assert!((velocity.points - 4.0).abs() < 0.01);
+        assert!((velocity.hours - 4.0).abs() < 0.01);
+
+        let recent = get_sprint_report(&db, &current.id, 1).unwrap().velocity.unwrap();
+        assert!((recent.points - 5.0).abs() < 0.01);
+        assert!(get_sprint_report(&db, "missing", 3).is_err());
+    }
+
     #[test]
     fn test_agent_workload() {
         let db = setup_test_db();
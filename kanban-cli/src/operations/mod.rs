@@ -1,9 +1,18 @@
 //! Database operations for kanban entities
 
+pub mod backup;
 pub mod blockers;
+pub mod checklists;
+pub mod export;
 pub mod features;
+pub mod iterations;
+pub mod labels;
 pub mod metrics;
+pub mod plans;
 pub mod tasks;
+pub mod templates;
+pub mod time;
+pub mod wip;
 
 use thiserror::Error;
 
@@ -27,6 +36,9 @@ pub enum OperationError {
 
     #[error("Agent unavailable: {0}")]
     AgentUnavailable(String),
+
+    #[error("WIP limit reached: {0}")]
+    WipLimit(String),
 }
 
 pub type Result<T> = std::result::Result<T, OperationError>;
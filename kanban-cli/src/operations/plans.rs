@@ -0,0 +1,580 @@
+//! Bulk task import and export through plan files
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::db::Database;
+use crate::models::{Plan, PlanTask, Task, TaskBuilder};
+
+use super::features::get_feature;
+use super::labels::{add_task_label, get_task_labels, normalize_label};
+use super::tasks::{
+    add_task_dependency, create_task, get_task, get_task_dependencies, list_tasks,
+};
+use super::{OperationError, Result};
+
+/// Columns of a CSV plan; list columns separate values with ';'
+const CSV_COLUMNS: &[&str] = &[
+    "key",
+    "title",
+    "feature",
+    "description",
+    "priority",
+    "estimate",
+    "labels",
+    "depends_on",
+];
+
+/// File format of a plan
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanFormat {
+    Json,
+    Yaml,
+    Csv,
+}
+
+impl PlanFormat {
+    /// Pick the format from a file extension
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let ext = path
+            .as_ref()
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        Self::from_name(&ext)
+    }
+
+    /// Parse a format name such as "json", "yaml", or "csv"
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "json" => Ok(PlanFormat::Json),
+            "yaml" | "yml" => Ok(PlanFormat::Yaml),
+            "csv" => Ok(PlanFormat::Csv),
+            other => Err(OperationError::Validation(format!(
+                "Unknown plan format '{}'; use json, yaml, or csv",
+                other
+            ))),
+        }
+    }
+}
+
+/// A task created by `import_plan`, with the plan key it came from
+#[derive(Debug, Clone)]
+pub struct ImportedTask {
+    pub key: Option<String>,
+    pub task: Task,
+}
+
+/// Parse plan file contents
+pub fn parse_plan(text: &str, format: PlanFormat) -> Result<Plan> {
+    match format {
+        PlanFormat::Json => serde_json::from_str(text)
+            .map_err(|e| OperationError::Validation(format!("Invalid JSON plan: {}", e))),
+        PlanFormat::Yaml => serde_yaml::from_str(text)
+            .map_err(|e| OperationError::Validation(format!("Invalid YAML plan: {}", e))),
+        PlanFormat::Csv => parse_csv_plan(text),
+    }
+}
+
+/// Write a plan in the given format
+pub fn write_plan(plan: &Plan, format: PlanFormat) -> String {
+    match format {
+        PlanFormat::Json => serde_json::to_string_pretty(plan).unwrap() + "\n",
+        PlanFormat::Yaml => serde_yaml::to_string(plan).unwrap(),
+        PlanFormat::Csv => write_csv_plan(plan),
+    }
+}
+
+/// Create every task in a plan in one transaction
+///
+/// Entries use their own feature, then `default_feature`, then the plan's
+/// feature. The whole plan is validated first, and nothing is created if
+/// any entry or dependency is invalid.
+pub fn import_plan(
+    db: &Database,
+    plan: &Plan,
+    default_feature: Option<&str>,
+    created_by: &str,
+) -> Result<Vec<ImportedTask>> {
+    if plan.tasks.is_empty() {
+        return Err(OperationError::Validation("Plan has no tasks".to_string()));
+    }
+
+    // Validate everything before writing
+    let mut keys = HashSet::new();
+    let mut resolved = Vec::new();
+    for (i, entry) in plan.tasks.iter().enumerate() {
+        let invalid = |msg: String| {
+            OperationError::Validation(format!("Entry {} ('{}'): {}", i + 1, entry.title, msg))
+        };
+
+        if entry.title.trim().is_empty() {
+            return Err(OperationError::Validation(format!(
+                "Entry {} has no title",
+                i + 1
+            )));
+        }
+        if let Some(key) = &entry.key {
+            if !keys.insert(key.as_str()) {
+                return Err(invalid(format!("duplicate key '{}'", key)));
+            }
+        }
+
+        let feature_id = entry
+            .feature
+            .as_deref()
+            .or(default_feature)
+            .or(plan.feature.as_deref())
+            .ok_or_else(|| invalid("no feature given".to_string()))?;
+        get_feature(db, feature_id).map_err(|e| invalid(e.to_string()))?;
+
+        let labels = entry
+            .labels
+            .iter()
+            .map(|l| normalize_label(l))
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| invalid(e.to_string()))?;
+
+        resolved.push((feature_id, labels));
+    }
+    for (i, entry) in plan.tasks.iter().enumerate() {
+        for dep in &entry.depends_on {
+            if entry.key.as_deref() == Some(dep.as_str()) {
+                return Err(OperationError::Dependency(format!(
+                    "Entry {} ('{}') depends on itself",
+                    i + 1,
+                    entry.title
+                )));
+            }
+            if !keys.contains(dep.as_str()) && get_task(db, dep).is_err() {
+                return Err(OperationError::Validation(format!(
+                    "Entry {} ('{}'): '{}' is neither a plan key nor an existing task",
+                    i + 1,
+                    entry.title,
+                    dep
+                )));
+            }
+        }
+    }
+
+    let tx = db.conn().unchecked_transaction()?;
+
+    let mut created = Vec::new();
+    let mut by_key: HashMap<&str, String> = HashMap::new();
+    for (entry, (feature_id, labels)) in plan.tasks.iter().zip(&resolved) {
+        let mut builder = TaskBuilder::new()
+            .feature_id(*feature_id)
+            .title(entry.title.trim());
+        if let Some(desc) = &entry.description {
+            builder = builder.description(desc);
+        }
+        if let Some(priority) = entry.priority {
+            builder = builder.priority(priority);
+        }
+        if let Some(hours) = entry.estimate {
+            builder = builder.estimated_hours(hours);
+        }
+        let request = builder
+            .build()
+            .map_err(|e| OperationError::Validation(e.to_string()))?;
+
+        let task = create_task(db, request)?;
+        for label in labels {
+            add_task_label(db, &task.id, label, created_by)?;
+        }
+        if let Some(key) = &entry.key {
+            by_key.insert(key, task.id.clone());
+        }
+        created.push(ImportedTask {
+            key: entry.key.clone(),
+            task,
+        });
+    }
+
+    // Dependencies go in once every task exists, so entries can be in any order
+    for (entry, imported) in plan.tasks.iter().zip(&created) {
+        for dep in &entry.depends_on {
+            let dep_id = by_key.get(dep.as_str()).unwrap_or(dep);
+            add_task_dependency(db, &imported.task.id, dep_id)?;
+        }
+    }
+
+    tx.commit()?;
+    Ok(created)
+}
+
+/// Export a feature's unarchived tasks as a plan
+///
+/// Task IDs become the plan keys, so importing the plan elsewhere recreates
+/// the same dependency structure. Only planning fields are exported; status,
+/// agents, and history are left behind.
+pub fn export_plan(db: &Database, feature_id: &str) -> Result<Plan> {
+    get_feature(db, feature_id)?;
+
+    let mut tasks = Vec::new();
+    for task in list_tasks(db, Some(feature_id), None, None, &[])? {
+        let depends_on = get_task_dependencies(db, &task.id)?
+            .into_iter()
+            .map(|dep| dep.id)
+            .collect();
+        tasks.push(PlanTask {
+            labels: get_task_labels(db, &task.id)?,
+            key: Some(task.id),
+            title: task.title,
+            feature: None,
+            description: task.description,
+            priority: Some(task.priority),
+            estimate: task.estimated_hours,
+            depends_on,
+        });
+    }
+
+    Ok(Plan {
+        feature: Some(feature_id.to_string()),
+        tasks,
+    })
+}
+
+/// Parse a CSV plan with a header row naming the columns
+fn parse_csv_plan(text: &str) -> Result<Plan> {
+    let mut records = parse_csv(text)?.into_iter();
+    let header = records
+        .next()
+        .ok_or_else(|| OperationError::Validation("CSV plan is empty".to_string()))?;
+
+    let mut columns = Vec::new();
+    for name in &header {
+        let name = name.trim().to_lowercase();
+        if !CSV_COLUMNS.contains(&name.as_str()) {
+            return Err(OperationError::Validation(format!(
+                "Unknown CSV column '{}' (expected {})",
+                name,
+                CSV_COLUMNS.join(", ")
+            )));
+        }
+        columns.push(name);
+    }
+    if !columns.iter().any(|c| c == "title") {
+        return Err(OperationError::Validation(
+            "CSV plan needs a 'title' column".to_string(),
+        ));
+    }
+
+    let mut tasks = Vec::new();
+    for (row, record) in records.enumerate() {
+        let invalid = |msg: String| {
+            OperationError::Validation(format!("CSV row {}: {}", row + 2, msg))
+        };
+        let mut entry = PlanTask::default();
+        for (column, value) in columns.iter().zip(record.iter()) {
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+            let list = || {
+                value
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|v| !v.is_empty())
+                    .map(String::from)
+                    .collect()
+            };
+            match column.as_str() {
+                "key" => entry.key = Some(value.to_string()),
+                "title" => entry.title = value.to_string(),
+                "feature" => entry.feature = Some(value.to_string()),
+                "description" => entry.description = Some(value.to_string()),
+                "priority" => {
+                    entry.priority = Some(
+                        value
+                            .parse()
+                            .map_err(|_| invalid(format!("invalid priority '{}'", value)))?,
+                    )
+                }
+                "estimate" => {
+                    entry.estimate = Some(
+                        value
+                            .parse()
+                            .map_err(|_| invalid(format!("invalid estimate '{}'", value)))?,
+                    )
+                }
+                "labels" => entry.labels = list(),
+                "depends_on" => entry.depends_on = list(),
+                _ => unreachable!("columns are checked against CSV_COLUMNS"),
+            }
+        }
+        tasks.push(entry);
+    }
+
+    Ok(Plan {
+        feature: None,
+        tasks,
+    })
+}
+
+/// Write a plan as CSV with every column
+///
+/// CSV has no place for the plan-level feature, so it is written on each row.
+fn write_csv_plan(plan: &Plan) -> String {
+    let mut output = CSV_COLUMNS.join(",");
+    output.push('\n');
+
+    for entry in &plan.tasks {
+        let fields = [
+            entry.key.clone().unwrap_or_default(),
+            entry.title.clone(),
+            entry
+                .feature
+                .clone()
+                .or_else(|| plan.feature.clone())
+                .unwrap_or_default(),
+            entry.description.clone().unwrap_or_default(),
+            entry.priority.map(|p| p.to_string()).unwrap_or_default(),
+            entry.estimate.map(|e| e.to_string()).unwrap_or_default(),
+            entry.labels.join(";"),
+            entry.depends_on.join(";"),
+        ];
+        let row: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        output.push_str(&row.join(","));
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Quote a CSV field when it contains separators, quotes, or line breaks
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) || value.trim() != value {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Split CSV text into records, honoring quoted fields
+///
+/// Blank lines are skipped. Quoted fields may contain commas, doubled
+/// quotes, and line breaks.
+fn parse_csv(text: &str) -> Result<Vec<Vec<String>>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|f| !f.is_empty()) {
+                    records.push(std::mem::take(&mut record));
+                } else {
+                    record.clear();
+                }
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(OperationError::Validation(
+            "CSV plan has an unterminated quoted field".to_string(),
+        ));
+    }
+    record.push(field);
+    if record.iter().any(|f| !f.is_empty()) {
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CreateFeatureRequest;
+    use crate::operations::{features, tasks};
+
+    fn setup_test_db() -> Database {
+        let db = Database::in_memory().unwrap();
+
+        features::create_feature(
+            &db,
+            CreateFeatureRequest {
+                name: "Test Feature".to_string(),
+                description: None,
+                color: None,
+            },
+        )
+        .unwrap();
+
+        db
+    }
+
+    fn entry(key: &str, title: &str, depends_on: &[&str]) -> PlanTask {
+        PlanTask {
+            key: Some(key.to_string()),
+            title: title.to_string(),
+            depends_on: depends_on.iter().map(|d| d.to_string()).collect(),
+            ..PlanTask::default()
+        }
+    }
+
+    #[test]
+    fn test_import_plan() {
+        let db = setup_test_db();
+
+        // Dependencies may point forward in the plan
+        let mut plan = Plan {
+            feature: Some("test-feature".to_string()),
+            tasks: vec![
+                entry("review", "Review", &["build", "docs"]),
+                entry("build", "Build", &[]),
+                entry("docs", "Docs", &["build"]),
+            ],
+        };
+        plan.tasks[1].labels = vec!["Core".to_string()];
+        plan.tasks[1].estimate = Some(3.0);
+
+        let created = import_plan(&db, &plan, None, "test").unwrap();
+        assert_eq!(created.len(), 3);
+        assert_eq!(created[0].key.as_deref(), Some("review"));
+        let review = &created[0].task;
+        let build = &created[1].task;
+        assert_eq!(build.estimated_hours, Some(3.0));
+        assert_eq!(get_task_labels(&db, &build.id).unwrap(), vec!["core"]);
+        assert_eq!(tasks::get_task_dependencies(&db, &review.id).unwrap().len(), 2);
+
+        // A later plan can depend on existing tasks by ID
+        let next = Plan {
+            feature: None,
+            tasks: vec![entry("ship", "Ship", &[review.id.as_str()])],
+        };
+        let shipped = import_plan(&db, &next, Some("test-feature"), "test").unwrap();
+        assert_eq!(
+            tasks::get_task_dependencies(&db, &shipped[0].task.id).unwrap()[0].id,
+            review.id
+        );
+    }
+
+    #[test]
+    fn test_import_plan_is_all_or_nothing() {
+        let db = setup_test_db();
+        let count = |db: &Database| {
+            tasks::list_tasks(db, None, None, None, &[]).unwrap().len()
+        };
+
+        let unknown = Plan {
+            feature: Some("test-feature".to_string()),
+            tasks: vec![entry("a", "A", &[]), entry("b", "B", &["missing"])],
+        };
+        assert!(matches!(
+            import_plan(&db, &unknown, None, "test"),
+            Err(OperationError::Validation(_))
+        ));
+
+        let cycle = Plan {
+            feature: Some("test-feature".to_string()),
+            tasks: vec![entry("a", "A", &["b"]), entry("b", "B", &["a"])],
+        };
+        assert!(matches!(
+            import_plan(&db, &cycle, None, "test"),
+            Err(OperationError::Dependency(_))
+        ));
+
+        let no_feature = Plan {
+            feature: None,
+            tasks: vec![entry("a", "A", &[])],
+        };
+        assert!(import_plan(&db, &no_feature, None, "test").is_err());
+        assert_eq!(count(&db), 0);
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let text = "title,key,depends_on,labels,estimate\n\
+                    \"Parse, then validate\",parse,,bug;core,2.5\n\
+                    \n\
+                    \"Say \"\"hi\"\"\nacross lines\",greet,parse,,\n";
+        let plan = parse_plan(text, PlanFormat::Csv).unwrap();
+        assert_eq!(plan.tasks.len(), 2);
+        assert_eq!(plan.tasks[0].title, "Parse, then validate");
+        assert_eq!(plan.tasks[0].labels, vec!["bug", "core"]);
+        assert_eq!(plan.tasks[0].estimate, Some(2.5));
+        assert_eq!(plan.tasks[1].title, "Say \"hi\"\nacross lines");
+        assert_eq!(plan.tasks[1].depends_on, vec!["parse"]);
+
+        let again = parse_plan(&write_plan(&plan, PlanFormat::Csv), PlanFormat::Csv).unwrap();
+        assert_eq!(again, plan);
+
+        assert!(parse_plan("name\nx\n", PlanFormat::Csv).is_err());
+        assert!(parse_plan("title\n\"open\n", PlanFormat::Csv).is_err());
+        assert!(PlanFormat::from_path("plan.txt").is_err());
+        assert_eq!(PlanFormat::from_path("plan.CSV").unwrap(), PlanFormat::Csv);
+        assert_eq!(PlanFormat::from_path("plan.yml").unwrap(), PlanFormat::Yaml);
+    }
+
+    #[test]
+    fn test_yaml_plan() {
+        let text = "\
+feature: test-feature
+tasks:
+  - key: build
+    title: Build the parser
+    estimate: 2.5
+    labels: [core]
+  - key: review
+    title: Review
+    depends_on:
+      - build
+";
+        let plan = parse_plan(text, PlanFormat::Yaml).unwrap();
+        assert_eq!(plan.feature.as_deref(), Some("test-feature"));
+        assert_eq!(plan.tasks[0].estimate, Some(2.5));
+        assert_eq!(plan.tasks[1].depends_on, vec!["build"]);
+        assert!(parse_plan("tasks: [", PlanFormat::Yaml).is_err());
+
+        let again = parse_plan(&write_plan(&plan, PlanFormat::Yaml), PlanFormat::Yaml).unwrap();
+        assert_eq!(again, plan);
+
+        let db = setup_test_db();
+        let created = import_plan(&db, &plan, None, "test").unwrap();
+        let deps = tasks::get_task_dependencies(&db, &created[1].task.id).unwrap();
+        assert_eq!(deps[0].id, created[0].task.id);
+    }
+
+    #[test]
+    fn test_export_plan_round_trip() {
+        let source = setup_test_db();
+        let plan = Plan {
+            feature: Some("test-feature".to_string()),
+            tasks: vec![entry("a", "A", &[]), entry("b", "B", &["a"])],
+        };
+        import_plan(&source, &plan, None, "test").unwrap();
+
+        let exported = export_plan(&source, "test-feature").unwrap();
+        assert_eq!(exported.tasks[1].depends_on, vec!["T-test-feature-001"]);
+
+        let json = write_plan(&exported, PlanFormat::Json);
+        let target = setup_test_db();
+        let created =
+            import_plan(&target, &parse_plan(&json, PlanFormat::Json).unwrap(), None, "test")
+                .unwrap();
+        let deps = tasks::get_task_dependencies(&target, &created[1].task.id).unwrap();
+        assert_eq!(deps[0].id, created[0].task.id);
+    }
+}
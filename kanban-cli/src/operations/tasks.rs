@@ -1,13 +1,15 @@
 //! Task CRUD operations
 
 use chrono::{DateTime, Utc};
-use rusqlite::{params, Row};
+use rusqlite::{params, OptionalExtension, Row};
 use uuid::Uuid;
 
 use crate::db::Database;
-use crate::models::{CreateTaskRequest, Task, TaskHistory};
+use crate::models::{CreateTaskRequest, Task, TaskComment, TaskHistory, UpdateTaskRequest};
 use crate::state_machine::{StateMachine, TaskStatus};
 
+use super::features::get_feature;
+use super::wip::{check_agent_wip_limit, check_wip_limits};
 use super::{OperationError, Result};
 
 /// Parse a task from a database row
@@ -47,14 +49,53 @@ fn parse_datetime(s: String) -> DateTime<Utc> {
         })
 }
 
+/// Last number handed out for an ID prefix, or 0 if none was recorded
+pub(crate) fn last_id_number(db: &Database, prefix: &str) -> Result<u32> {
+    Ok(db
+        .conn()
+        .query_row(
+            "SELECT last FROM id_sequences WHERE prefix = ?",
+            params![prefix],
+            |row| row.get(0),
+        )
+        .optional()?
+        .unwrap_or(0))
+}
+
+/// Record that `id`, made of `prefix` and a number, was handed out
+pub(crate) fn record_id_number(db: &Database, prefix: &str, id: &str) -> Result<()> {
+    let Some(number) = id.strip_prefix(prefix).and_then(|n| n.parse::<u32>().ok()) else {
+        return Ok(());
+    };
+    db.conn().execute(
+        "INSERT INTO id_sequences (prefix, last) VALUES (?1, ?2)
+         ON CONFLICT(prefix) DO UPDATE SET last = MAX(last, excluded.last)",
+        params![prefix, number],
+    )?;
+    Ok(())
+}
+
 /// Generate a task ID based on feature and sequence
+///
+/// Continues from the last number handed out for the feature's prefix, so
+/// deleting a task, even the newest one, never causes an ID to be handed out
+/// twice. Existing IDs are scanned too, for boards created before numbers
+/// were recorded. Matches on the ID prefix rather than the feature, since a
+/// task moved to another feature keeps its original ID.
 pub fn generate_task_id(db: &Database, feature_id: &str) -> Result<String> {
-    let count: i64 = db.conn().query_row(
-        "SELECT COUNT(*) FROM tasks WHERE feature_id = ?",
-        params![feature_id],
-        |row| row.get(0),
-    )?;
-    Ok(format!("T-{}-{:03}", feature_id, count + 1))
+    let prefix = format!("T-{}-", feature_id);
+    let mut stmt = db
+        .conn()
+        .prepare("SELECT id FROM tasks WHERE substr(id, 1, length(?1)) = ?1")?;
+    let last = stmt
+        .query_map(params![prefix], |row| row.get::<_, String>(0))?
+        .collect::<std::result::Result<Vec<_>, _>>()?
+        .iter()
+        .filter_map(|id| id.strip_prefix(&prefix)?.parse::<u32>().ok())
+        .max()
+        .unwrap_or(0)
+        .max(last_id_number(db, &prefix)?);
+    Ok(format!("{}{:03}", prefix, last + 1))
 }
 
 /// Create a new task
@@ -78,6 +119,7 @@ pub fn create_task(db: &Database, request: CreateTaskRequest) -> Result<Task> {
             now,
         ],
     )?;
+    record_id_number(db, &format!("T-{}-", request.feature_id), &task_id)?;
 
     // Add dependencies
     for dep_id in &request.dependencies {
@@ -107,13 +149,17 @@ pub fn get_task(db: &Database, task_id: &str) -> Result<Task> {
 }
 
 /// List tasks with optional filters
+///
+/// Tasks must carry every label in `labels` (already normalized).
 pub fn list_tasks(
     db: &Database,
     feature_id: Option<&str>,
     status: Option<TaskStatus>,
     agent_id: Option<&str>,
+    labels: &[String],
 ) -> Result<Vec<Task>> {
-    let mut sql = String::from("SELECT * FROM tasks WHERE 1=1");
+    let mut sql =
+        String::from("SELECT * FROM tasks WHERE id NOT IN (SELECT task_id FROM archived_tasks)");
     let mut params_vec: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
 
     if let Some(fid) = feature_id {
@@ -131,6 +177,11 @@ pub fn list_tasks(
         params_vec.push(Box::new(aid.to_string()));
     }
 
+    for label in labels {
+        sql.push_str(" AND id IN (SELECT task_id FROM task_labels WHERE label = ?)");
+        params_vec.push(Box::new(label.clone()));
+    }
+
     sql.push_str(" ORDER BY priority ASC, created_at ASC");
 
     let params_refs: Vec<&dyn rusqlite::ToSql> = params_vec.iter().map(|p| p.as_ref()).collect();
@@ -144,11 +195,16 @@ pub fn list_tasks(
 }
 
 /// Update task status with state machine validation
+///
+/// Moves that would exceed a WIP limit are refused unless `override_wip`
+/// is set. Automatic moves made when blockers are added or resolved pass
+/// `override_wip` and so skip the limits.
 pub fn update_task_status(
     db: &Database,
     task_id: &str,
     new_status: TaskStatus,
     changed_by: &str,
+    override_wip: bool,
 ) -> Result<Task> {
     let task = get_task(db, task_id)?;
 
@@ -160,6 +216,10 @@ pub fn update_task_status(
         )));
     }
 
+    if !override_wip {
+        check_wip_limits(db, &task, new_status)?;
+    }
+
     let now = Utc::now().to_rfc3339();
     let old_status = task.status.to_string();
     let new_status_str = new_status.to_string();
@@ -195,19 +255,76 @@ pub fn update_task_status(
     }
 
     // Record history
-    record_history(db, task_id, "status", Some(&old_status), &new_status_str, changed_by)?;
+    record_history(db, task_id, "status", Some(&old_status), Some(&new_status_str), changed_by)?;
+
+    get_task(db, task_id)
+}
+
+/// Force a task back to an earlier status, bypassing the state machine
+///
+/// Used to undo a mistaken move. Completion and start times are cleared
+/// when the task goes back before the point that set them.
+pub fn revert_task_status(
+    db: &Database,
+    task_id: &str,
+    status: TaskStatus,
+    changed_by: &str,
+) -> Result<Task> {
+    let task = get_task(db, task_id)?;
+    if task.status == status {
+        return Ok(task);
+    }
+
+    let now = Utc::now().to_rfc3339();
+    let completed_at = if status == TaskStatus::Done {
+        task.completed_at.map(|t| t.to_rfc3339())
+    } else {
+        None
+    };
+    let started_at = if status == TaskStatus::Todo {
+        None
+    } else {
+        task.started_at.map(|t| t.to_rfc3339())
+    };
+
+    db.conn().execute(
+        "UPDATE tasks SET status = ?, started_at = ?, completed_at = ?, updated_at = ? WHERE id = ?",
+        params![status.to_string(), started_at, completed_at, now, task_id],
+    )?;
+
+    record_history(
+        db,
+        task_id,
+        "status",
+        Some(task.status.as_str()),
+        Some(status.as_str()),
+        changed_by,
+    )?;
 
     get_task(db, task_id)
 }
 
 /// Assign a task to an agent
-pub fn assign_task(db: &Database, task_id: &str, agent_id: &str, changed_by: &str) -> Result<Task> {
+///
+/// Refused when the agent is at capacity, or when the agent's WIP limit for
+/// the task's current status is reached unless `override_wip` is set.
+pub fn assign_task(
+    db: &Database,
+    task_id: &str,
+    agent_id: &str,
+    changed_by: &str,
+    override_wip: bool,
+) -> Result<Task> {
     let task = get_task(db, task_id)?;
     let now = Utc::now().to_rfc3339();
 
-    // Check agent exists and has capacity
+    // Check agent exists and has capacity (archived tasks do not count)
     let current_tasks: i64 = db.conn().query_row(
-        "SELECT COUNT(*) FROM tasks WHERE assigned_agent = ? AND status IN ('in-progress', 'blocked')",
+        r#"
+        SELECT COUNT(*) FROM tasks
+        WHERE assigned_agent = ? AND status IN ('in-progress', 'blocked')
+          AND id NOT IN (SELECT task_id FROM archived_tasks)
+        "#,
         params![agent_id],
         |row| row.get(0),
     )?;
@@ -227,6 +344,9 @@ pub fn assign_task(db: &Database, task_id: &str, agent_id: &str, changed_by: &st
             agent_id, current_tasks, max_tasks
         )));
     }
+    if !override_wip {
+        check_agent_wip_limit(db, agent_id, task.status, task_id)?;
+    }
 
     db.conn().execute(
         "UPDATE tasks SET assigned_agent = ?, updated_at = ? WHERE id = ?",
@@ -234,6 +354,38 @@ pub fn assign_task(db: &Database, task_id: &str, agent_id: &str, changed_by: &st
     )?;
 
     // Record history
+    record_history(
+        db,
+        task_id,
+        "assigned_agent",
+        task.assigned_agent.as_deref(),
+        Some(agent_id),
+        changed_by,
+    )?;
+
+    get_task(db, task_id)
+}
+
+/// Restore a task's previous assignment, skipping the capacity check
+///
+/// Used to undo a mistaken assignment; `None` leaves the task unassigned.
+pub fn revert_task_assignment(
+    db: &Database,
+    task_id: &str,
+    agent_id: Option<&str>,
+    changed_by: &str,
+) -> Result<Task> {
+    let task = get_task(db, task_id)?;
+    if task.assigned_agent.as_deref() == agent_id {
+        return Ok(task);
+    }
+    let now = Utc::now().to_rfc3339();
+
+    db.conn().execute(
+        "UPDATE tasks SET assigned_agent = ?, updated_at = ? WHERE id = ?",
+        params![agent_id, now, task_id],
+    )?;
+
     record_history(
         db,
         task_id,
@@ -246,6 +398,51 @@ pub fn assign_task(db: &Database, task_id: &str, agent_id: &str, changed_by: &st
     get_task(db, task_id)
 }
 
+/// Update several fields of a task at once
+///
+/// Every field is validated before anything is written, and the changes
+/// are saved in one transaction, so a bad value leaves the task untouched.
+/// Each changed field gets its own history entry.
+pub fn update_task(
+    db: &Database,
+    task_id: &str,
+    request: &UpdateTaskRequest,
+    changed_by: &str,
+) -> Result<Task> {
+    let task = get_task(db, task_id)?;
+    if request.title.as_deref().is_some_and(|t| t.trim().is_empty()) {
+        return Err(OperationError::Validation("Title cannot be empty".to_string()));
+    }
+    if request.estimated_hours.flatten().is_some_and(|h| h < 0.0) {
+        return Err(OperationError::Validation(
+            "Estimate cannot be negative".to_string(),
+        ));
+    }
+    if let Some(feature_id) = &request.feature_id {
+        get_feature(db, feature_id)?;
+    }
+
+    let tx = db.conn().unchecked_transaction()?;
+    if let Some(title) = &request.title {
+        update_task_title(db, task_id, title, changed_by)?;
+    }
+    if let Some(description) = &request.description {
+        update_task_description(db, task_id, description.as_deref(), changed_by)?;
+    }
+    if let Some(priority) = request.priority.filter(|p| *p != task.priority) {
+        update_task_priority(db, task_id, priority, changed_by)?;
+    }
+    if let Some(estimate) = request.estimated_hours {
+        update_task_estimate(db, task_id, estimate, changed_by)?;
+    }
+    if let Some(feature_id) = &request.feature_id {
+        update_task_feature(db, task_id, feature_id, changed_by)?;
+    }
+    tx.commit()?;
+
+    get_task(db, task_id)
+}
+
 /// Update task priority
 pub fn update_task_priority(
     db: &Database,
@@ -266,20 +463,269 @@ pub fn update_task_priority(
         task_id,
         "priority",
         Some(&task.priority.to_string()),
-        &priority.to_string(),
+        Some(&priority.to_string()),
         changed_by,
     )?;
 
     get_task(db, task_id)
 }
 
+/// Update task title
+pub fn update_task_title(
+    db: &Database,
+    task_id: &str,
+    title: &str,
+    changed_by: &str,
+) -> Result<Task> {
+    let task = get_task(db, task_id)?;
+
+    if title.trim().is_empty() {
+        return Err(OperationError::Validation("Title cannot be empty".to_string()));
+    }
+    if task.title == title {
+        return Ok(task);
+    }
+
+    let now = Utc::now().to_rfc3339();
+    db.conn().execute(
+        "UPDATE tasks SET title = ?, updated_at = ? WHERE id = ?",
+        params![title, now, task_id],
+    )?;
+
+    record_history(db, task_id, "title", Some(&task.title), Some(title), changed_by)?;
+
+    get_task(db, task_id)
+}
+
+/// Update task description (None clears it)
+pub fn update_task_description(
+    db: &Database,
+    task_id: &str,
+    description: Option<&str>,
+    changed_by: &str,
+) -> Result<Task> {
+    let task = get_task(db, task_id)?;
+    if task.description.as_deref() == description {
+        return Ok(task);
+    }
+
+    let now = Utc::now().to_rfc3339();
+    db.conn().execute(
+        "UPDATE tasks SET description = ?, updated_at = ? WHERE id = ?",
+        params![description, now, task_id],
+    )?;
+
+    record_history(
+        db,
+        task_id,
+        "description",
+        task.description.as_deref(),
+        description,
+        changed_by,
+    )?;
+
+    get_task(db, task_id)
+}
+
+/// Update task estimated hours (None clears it)
+pub fn update_task_estimate(
+    db: &Database,
+    task_id: &str,
+    estimated_hours: Option<f64>,
+    changed_by: &str,
+) -> Result<Task> {
+    let task = get_task(db, task_id)?;
+
+    if estimated_hours.is_some_and(|h| h < 0.0) {
+        return Err(OperationError::Validation(
+            "Estimate cannot be negative".to_string(),
+        ));
+    }
+    if task.estimated_hours == estimated_hours {
+        return Ok(task);
+    }
+
+    let now = Utc::now().to_rfc3339();
+    db.conn().execute(
+        "UPDATE tasks SET estimated_hours = ?, updated_at = ? WHERE id = ?",
+        params![estimated_hours, now, task_id],
+    )?;
+
+    record_history(
+        db,
+        task_id,
+        "estimated_hours",
+        task.estimated_hours.map(|h| h.to_string()).as_deref(),
+        estimated_hours.map(|h| h.to_string()).as_deref(),
+        changed_by,
+    )?;
+
+    get_task(db, task_id)
+}
+
+/// Move a task to a different feature
+///
+/// The task keeps its ID; dependencies on tasks in the old feature are kept
+/// and show up as external dependencies in the dependency graph.
+pub fn update_task_feature(
+    db: &Database,
+    task_id: &str,
+    feature_id: &str,
+    changed_by: &str,
+) -> Result<Task> {
+    let task = get_task(db, task_id)?;
+    get_feature(db, feature_id)?;
+    if task.feature_id == feature_id {
+        return Ok(task);
+    }
+
+    let now = Utc::now().to_rfc3339();
+    db.conn().execute(
+        "UPDATE tasks SET feature_id = ?, updated_at = ? WHERE id = ?",
+        params![feature_id, now, task_id],
+    )?;
+
+    record_history(
+        db,
+        task_id,
+        "feature_id",
+        Some(&task.feature_id),
+        Some(feature_id),
+        changed_by,
+    )?;
+
+    get_task(db, task_id)
+}
+
+/// Add hours to a task's actual hours
+pub fn add_actual_hours(
+    db: &Database,
+    task_id: &str,
+    hours: f64,
+    changed_by: &str,
+) -> Result<Task> {
+    let task = get_task(db, task_id)?;
+    let total = task.actual_hours.unwrap_or(0.0) + hours;
+    let now = Utc::now().to_rfc3339();
+
+    db.conn().execute(
+        "UPDATE tasks SET actual_hours = ?, updated_at = ? WHERE id = ?",
+        params![total, now, task_id],
+    )?;
+
+    record_history(
+        db,
+        task_id,
+        "actual_hours",
+        task.actual_hours.map(|h| h.to_string()).as_deref(),
+        Some(&total.to_string()),
+        changed_by,
+    )?;
+
+    get_task(db, task_id)
+}
+
+/// Archive a task, hiding it from boards, lists, and metrics
+///
+/// The task row and its history are kept, so `task show` and `task history`
+/// still work.
+pub fn archive_task(db: &Database, task_id: &str, changed_by: &str) -> Result<Task> {
+    let task = get_task(db, task_id)?;
+    if is_task_archived(db, task_id)? {
+        return Err(OperationError::Validation(format!(
+            "Task is already archived: {}",
+            task_id
+        )));
+    }
+
+    db.conn().execute(
+        "INSERT INTO archived_tasks (task_id, archived_by, archived_at) VALUES (?, ?, ?)",
+        params![task_id, changed_by, Utc::now().to_rfc3339()],
+    )?;
+    record_history(db, task_id, "archived", None, Some("true"), changed_by)?;
+
+    Ok(task)
+}
+
+/// Check if a task has been archived
+pub fn is_task_archived(db: &Database, task_id: &str) -> Result<bool> {
+    let count: i64 = db.conn().query_row(
+        "SELECT COUNT(*) FROM archived_tasks WHERE task_id = ?",
+        params![task_id],
+        |row| row.get(0),
+    )?;
+    Ok(count > 0)
+}
+
+/// Permanently delete a task
+///
+/// Refuses tasks with history, comments, time entries, dependencies, blockers,
+/// checklist items, or sprint commitments unless `force` is set, in which
+/// case those records are deleted too. Labels and template links always go
+/// with the task.
+pub fn delete_task(db: &Database, task_id: &str, force: bool) -> Result<()> {
+    get_task(db, task_id)?;
+
+    if !force {
+        let count = |sql: &str| -> Result<i64> {
+            Ok(db.conn().query_row(sql, params![task_id], |row| row.get(0))?)
+        };
+        let history = count("SELECT COUNT(*) FROM task_history WHERE task_id = ?1")?
+            + count("SELECT COUNT(*) FROM task_comments WHERE task_id = ?1")?
+            + count("SELECT COUNT(*) FROM time_entries WHERE task_id = ?1")?;
+        let dependencies = count(
+            "SELECT COUNT(*) FROM task_dependencies WHERE task_id = ?1 OR depends_on_task_id = ?1",
+        )?;
+        let blockers =
+            count("SELECT COUNT(*) FROM blockers WHERE task_id = ?1 OR blocking_task_id = ?1")?;
+        let checklist = count("SELECT COUNT(*) FROM checklist_items WHERE task_id = ?1")?;
+        let sprints = count("SELECT COUNT(*) FROM iteration_tasks WHERE task_id = ?1")?;
+
+        if history + dependencies + blockers + checklist + sprints > 0 {
+            return Err(OperationError::Dependency(format!(
+                "Task {} still has records (history/comments/time: {}, dependencies: {}, \
+                 blockers: {}, checklist items: {}, sprints: {}); use --force to delete them \
+                 too, or archive the task instead",
+                task_id, history, dependencies, blockers, checklist, sprints
+            )));
+        }
+    }
+
+    let tx = db.conn().unchecked_transaction()?;
+    tx.execute("DELETE FROM task_history WHERE task_id = ?1", params![task_id])?;
+    tx.execute("DELETE FROM task_comments WHERE task_id = ?1", params![task_id])?;
+    tx.execute("DELETE FROM time_entries WHERE task_id = ?1", params![task_id])?;
+    tx.execute("DELETE FROM task_labels WHERE task_id = ?1", params![task_id])?;
+    tx.execute("DELETE FROM checklist_items WHERE task_id = ?1", params![task_id])?;
+    tx.execute("DELETE FROM template_tasks WHERE task_id = ?1", params![task_id])?;
+    tx.execute("DELETE FROM iteration_tasks WHERE task_id = ?1", params![task_id])?;
+    tx.execute(
+        "DELETE FROM task_dependencies WHERE task_id = ?1 OR depends_on_task_id = ?1",
+        params![task_id],
+    )?;
+    tx.execute("DELETE FROM blockers WHERE task_id = ?1", params![task_id])?;
+    tx.execute(
+        "UPDATE blockers SET blocking_task_id = NULL WHERE blocking_task_id = ?1",
+        params![task_id],
+    )?;
+    tx.execute(
+        "UPDATE agent_executions SET task_id = NULL WHERE task_id = ?1",
+        params![task_id],
+    )?;
+    tx.execute("DELETE FROM archived_tasks WHERE task_id = ?1", params![task_id])?;
+    tx.execute("DELETE FROM tasks WHERE id = ?1", params![task_id])?;
+    tx.commit()?;
+
+    Ok(())
+}
+
 /// Record a change in task history
-fn record_history(
+pub(crate) fn record_history(
     db: &Database,
     task_id: &str,
     field: &str,
     old_value: Option<&str>,
-    new_value: &str,
+    new_value: Option<&str>,
     changed_by: &str,
 ) -> Result<()> {
     let now = Utc::now().to_rfc3339();
@@ -347,6 +793,45 @@ pub fn add_task_dependency(db: &Database, task_id: &str, depends_on: &str) -> Re
     Ok(())
 }
 
+/// Get all dependency edges between tasks of a feature as (task_id, depends_on_task_id)
+pub fn get_feature_dependencies(db: &Database, feature_id: &str) -> Result<Vec<(String, String)>> {
+    let mut stmt = db.conn().prepare(
+        "SELECT d.task_id, d.depends_on_task_id FROM task_dependencies d
+         JOIN tasks t ON t.id = d.task_id
+         JOIN tasks dep ON dep.id = d.depends_on_task_id
+         WHERE t.feature_id = ? AND dep.feature_id = ?
+         ORDER BY d.task_id, d.depends_on_task_id",
+    )?;
+
+    let edges = stmt
+        .query_map(params![feature_id, feature_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    Ok(edges)
+}
+
+/// Get dependencies of a feature's tasks on tasks in other features
+///
+/// Returns (task_id, upstream task) pairs, so callers can tell whether the
+/// upstream work is done.
+pub fn get_external_dependencies(db: &Database, feature_id: &str) -> Result<Vec<(String, Task)>> {
+    let mut stmt = db.conn().prepare(
+        "SELECT d.task_id AS dependent_id, dep.* FROM task_dependencies d
+         JOIN tasks t ON t.id = d.task_id
+         JOIN tasks dep ON dep.id = d.depends_on_task_id
+         WHERE t.feature_id = ?1 AND dep.feature_id != ?1
+         ORDER BY d.task_id, d.depends_on_task_id",
+    )?;
+
+    let edges = stmt
+        .query_map(params![feature_id], |row| {
+            Ok((row.get("dependent_id")?, task_from_row(row)?))
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    Ok(edges)
+}
+
 /// Check if adding a dependency would create a cycle
 fn would_create_cycle(db: &Database, task_id: &str, depends_on: &str) -> Result<bool> {
     // Simple cycle check: see if depends_on already depends on task_id (directly or indirectly)
@@ -372,16 +857,54 @@ fn would_create_cycle(db: &Database, task_id: &str, depends_on: &str) -> Result<
 }
 
 /// Add a comment to a task
-pub fn add_task_comment(db: &Database, task_id: &str, author: &str, content: &str) -> Result<()> {
+pub fn add_task_comment(
+    db: &Database,
+    task_id: &str,
+    author: &str,
+    content: &str,
+) -> Result<TaskComment> {
+    get_task(db, task_id)?;
+
+    if content.trim().is_empty() {
+        return Err(OperationError::Validation("Comment cannot be empty".to_string()));
+    }
+
     let id = Uuid::new_v4().to_string();
-    let now = Utc::now().to_rfc3339();
+    let now = Utc::now();
 
     db.conn().execute(
         "INSERT INTO task_comments (id, task_id, author, content, created_at) VALUES (?, ?, ?, ?, ?)",
-        params![id, task_id, author, content, now],
+        params![id, task_id, author, content, now.to_rfc3339()],
     )?;
 
-    Ok(())
+    Ok(TaskComment {
+        id,
+        task_id: task_id.to_string(),
+        author: author.to_string(),
+        content: content.to_string(),
+        created_at: now,
+    })
+}
+
+/// Get comments on a task, oldest first
+pub fn get_task_comments(db: &Database, task_id: &str) -> Result<Vec<TaskComment>> {
+    let mut stmt = db.conn().prepare(
+        "SELECT id, task_id, author, content, created_at FROM task_comments WHERE task_id = ? ORDER BY created_at ASC",
+    )?;
+
+    let comments = stmt
+        .query_map(params![task_id], |row| {
+            Ok(TaskComment {
+                id: row.get("id")?,
+                task_id: row.get("task_id")?,
+                author: row.get("author")?,
+                content: row.get("content")?,
+                created_at: parse_datetime(row.get::<_, String>("created_at")?),
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    Ok(comments)
 }
 
 #[cfg(test)]
@@ -433,16 +956,17 @@ mod tests {
         let task = create_task(&db, request).unwrap();
 
         // Valid transition: todo -> in-progress
-        let task = update_task_status(&db, &task.id, TaskStatus::InProgress, "test").unwrap();
+        let task = update_task_status(&db, &task.id, TaskStatus::InProgress, "test", false)
+            .unwrap();
         assert_eq!(task.status, TaskStatus::InProgress);
         assert!(task.started_at.is_some());
 
         // Valid transition: in-progress -> in-qa
-        let task = update_task_status(&db, &task.id, TaskStatus::InQa, "test").unwrap();
+        let task = update_task_status(&db, &task.id, TaskStatus::InQa, "test", false).unwrap();
         assert_eq!(task.status, TaskStatus::InQa);
 
         // Valid transition: in-qa -> done
-        let task = update_task_status(&db, &task.id, TaskStatus::Done, "test").unwrap();
+        let task = update_task_status(&db, &task.id, TaskStatus::Done, "test", false).unwrap();
         assert_eq!(task.status, TaskStatus::Done);
         assert!(task.completed_at.is_some());
     }
@@ -459,7 +983,7 @@ mod tests {
         let task = create_task(&db, request).unwrap();
 
         // Invalid transition: todo -> done
-        let result = update_task_status(&db, &task.id, TaskStatus::Done, "test");
+        let result = update_task_status(&db, &task.id, TaskStatus::Done, "test", false);
         assert!(result.is_err());
     }
 
@@ -473,11 +997,346 @@ mod tests {
             .unwrap();
 
         let task = create_task(&db, request).unwrap();
-        update_task_status(&db, &task.id, TaskStatus::InProgress, "tester").unwrap();
+        update_task_status(&db, &task.id, TaskStatus::InProgress, "tester", false).unwrap();
 
         let history = get_task_history(&db, &task.id).unwrap();
         assert!(!history.is_empty());
         assert_eq!(history[0].field_changed, "status");
         assert_eq!(history[0].changed_by, "tester");
     }
+
+    #[test]
+    fn test_update_task_fields() {
+        let db = setup_test_db();
+        let request = TaskBuilder::new()
+            .feature_id("test-feature")
+            .title("Test task")
+            .build()
+            .unwrap();
+
+        let task = create_task(&db, request).unwrap();
+        let task = update_task_title(&db, &task.id, "Renamed task", "tester").unwrap();
+        let task = update_task_description(&db, &task.id, Some("Details"), "tester").unwrap();
+        let task = update_task_estimate(&db, &task.id, Some(3.5), "tester").unwrap();
+
+        assert_eq!(task.title, "Renamed task");
+        assert_eq!(task.description.as_deref(), Some("Details"));
+        assert_eq!(task.estimated_hours, Some(3.5));
+
+        // Unchanged values do not add history entries
+        update_task_title(&db, &task.id, "Renamed task", "tester").unwrap();
+        let history = get_task_history(&db, &task.id).unwrap();
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].old_value.as_deref(), Some("Test task"));
+        assert_eq!(history[1].old_value, None);
+
+        assert!(update_task_title(&db, &task.id, "  ", "tester").is_err());
+
+        features::create_feature(
+            &db,
+            crate::models::CreateFeatureRequest {
+                name: "Other Feature".to_string(),
+                description: None,
+                color: None,
+            },
+        )
+        .unwrap();
+        let moved = update_task_feature(&db, &task.id, "other-feature", "tester").unwrap();
+        assert_eq!(moved.feature_id, "other-feature");
+        assert_eq!(moved.id, task.id);
+        assert!(update_task_feature(&db, &task.id, "missing", "tester").is_err());
+        let history = get_task_history(&db, &task.id).unwrap();
+        assert!(history.iter().any(|h| h.field_changed == "feature_id"));
+    }
+
+    #[test]
+    fn test_update_task_is_all_or_nothing() {
+        let db = setup_test_db();
+        let request = TaskBuilder::new()
+            .feature_id("test-feature")
+            .title("Original")
+            .priority(5)
+            .estimated_hours(2.0)
+            .build()
+            .unwrap();
+        let task = create_task(&db, request).unwrap();
+
+        let bad = UpdateTaskRequest {
+            title: Some("Renamed".to_string()),
+            estimated_hours: Some(Some(-1.0)),
+            ..Default::default()
+        };
+        assert!(update_task(&db, &task.id, &bad, "tester").is_err());
+        assert_eq!(get_task(&db, &task.id).unwrap().title, "Original");
+
+        let unknown_feature = UpdateTaskRequest {
+            title: Some("Renamed".to_string()),
+            feature_id: Some("nope".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            update_task(&db, &task.id, &unknown_feature, "tester"),
+            Err(OperationError::NotFound(_))
+        ));
+        assert_eq!(get_task(&db, &task.id).unwrap().title, "Original");
+
+        // Unset fields stay as they were
+        let request = UpdateTaskRequest {
+            title: Some("Renamed".to_string()),
+            estimated_hours: Some(None),
+            ..Default::default()
+        };
+        let updated = update_task(&db, &task.id, &request, "tester").unwrap();
+        assert_eq!(updated.title, "Renamed");
+        assert_eq!(updated.priority, 5);
+        assert_eq!(updated.estimated_hours, None);
+        assert_eq!(get_task_history(&db, &task.id).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_task_ids_after_feature_move() {
+        let db = setup_test_db();
+        features::create_feature(
+            &db,
+            crate::models::CreateFeatureRequest {
+                name: "Other Feature".to_string(),
+                description: None,
+                color: None,
+            },
+        )
+        .unwrap();
+        let new_task = |feature_id: &str, title: &str| {
+            let request = TaskBuilder::new()
+                .feature_id(feature_id)
+                .title(title)
+                .build()
+                .unwrap();
+            create_task(&db, request).unwrap()
+        };
+
+        new_task("test-feature", "First");
+        let last = new_task("test-feature", "Second");
+        update_task_feature(&db, &last.id, "other-feature", "tester").unwrap();
+
+        // The moved task keeps its ID, so the old feature continues after it
+        assert_eq!(new_task("test-feature", "Third").id, "T-test-feature-003");
+        assert_eq!(new_task("other-feature", "Other").id, "T-other-feature-001");
+    }
+
+    #[test]
+    fn test_task_comments() {
+        let db = setup_test_db();
+        let request = TaskBuilder::new()
+            .feature_id("test-feature")
+            .title("Test task")
+            .build()
+            .unwrap();
+
+        let task = create_task(&db, request).unwrap();
+        add_task_comment(&db, &task.id, "tester", "First").unwrap();
+        add_task_comment(&db, &task.id, "reviewer", "Second").unwrap();
+
+        let comments = get_task_comments(&db, &task.id).unwrap();
+        assert_eq!(comments.len(), 2);
+        assert_eq!(comments[0].content, "First");
+        assert_eq!(comments[1].author, "reviewer");
+
+        assert!(add_task_comment(&db, &task.id, "tester", "  ").is_err());
+        assert!(add_task_comment(&db, "T-missing", "tester", "Hello").is_err());
+    }
+
+    #[test]
+    fn test_feature_dependencies() {
+        let db = setup_test_db();
+        let first = create_task(
+            &db,
+            TaskBuilder::new()
+                .feature_id("test-feature")
+                .title("First")
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+        let second = create_task(
+            &db,
+            TaskBuilder::new()
+                .feature_id("test-feature")
+                .title("Second")
+                .depends_on(&first.id)
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+
+        let edges = get_feature_dependencies(&db, "test-feature").unwrap();
+        assert_eq!(edges, vec![(second.id.clone(), first.id.clone())]);
+        assert!(get_external_dependencies(&db, "test-feature").unwrap().is_empty());
+
+        // Moving the upstream task away turns the edge into an external one
+        features::create_feature(
+            &db,
+            crate::models::CreateFeatureRequest {
+                name: "Other Feature".to_string(),
+                description: None,
+                color: None,
+            },
+        )
+        .unwrap();
+        update_task_feature(&db, &first.id, "other-feature", "tester").unwrap();
+        assert!(get_feature_dependencies(&db, "test-feature").unwrap().is_empty());
+        let external = get_external_dependencies(&db, "test-feature").unwrap();
+        assert_eq!(external.len(), 1);
+        assert_eq!(external[0].0, second.id);
+        assert_eq!(external[0].1.id, first.id);
+
+        // Reverse edge would be circular
+        assert!(add_task_dependency(&db, &first.id, &second.id).is_err());
+    }
+
+    #[test]
+    fn test_revert_task_status_and_assignment() {
+        let db = setup_test_db();
+        let request = TaskBuilder::new()
+            .feature_id("test-feature")
+            .title("Test task")
+            .build()
+            .unwrap();
+
+        let task = create_task(&db, request).unwrap();
+        update_task_status(&db, &task.id, TaskStatus::InProgress, "test", false).unwrap();
+        update_task_status(&db, &task.id, TaskStatus::InQa, "test", false).unwrap();
+        update_task_status(&db, &task.id, TaskStatus::Done, "test", false).unwrap();
+
+        let task = revert_task_status(&db, &task.id, TaskStatus::InQa, "test").unwrap();
+        assert_eq!(task.status, TaskStatus::InQa);
+        assert!(task.completed_at.is_none());
+        assert!(task.started_at.is_some());
+
+        let task = revert_task_status(&db, &task.id, TaskStatus::Todo, "test").unwrap();
+        assert!(task.started_at.is_none());
+
+        assign_task(&db, &task.id, "cli_developer", "test", false).unwrap();
+        let task = revert_task_assignment(&db, &task.id, None, "test").unwrap();
+        assert_eq!(task.assigned_agent, None);
+
+        let history = get_task_history(&db, &task.id).unwrap();
+        assert_eq!(history.len(), 7);
+    }
+
+    #[test]
+    fn test_archive_task() {
+        let db = setup_test_db();
+        let request = TaskBuilder::new()
+            .feature_id("test-feature")
+            .title("Test task")
+            .build()
+            .unwrap();
+
+        let task = create_task(&db, request).unwrap();
+        archive_task(&db, &task.id, "tester").unwrap();
+
+        assert!(is_task_archived(&db, &task.id).unwrap());
+        assert!(list_tasks(&db, Some("test-feature"), None, None, &[])
+            .unwrap()
+            .is_empty());
+        // Still reachable directly, with the archive recorded in history
+        assert_eq!(get_task(&db, &task.id).unwrap().title, "Test task");
+        assert_eq!(get_task_history(&db, &task.id).unwrap()[0].field_changed, "archived");
+        assert!(archive_task(&db, &task.id, "tester").is_err());
+    }
+
+    #[test]
+    fn test_archived_tasks_free_agent_capacity() {
+        let db = setup_test_db();
+        db.conn()
+            .execute(
+                "UPDATE agents SET max_concurrent_tasks = 1 WHERE id = 'parser_developer'",
+                [],
+            )
+            .unwrap();
+        let new_task = |title: &str| {
+            let request = TaskBuilder::new()
+                .feature_id("test-feature")
+                .title(title)
+                .build()
+                .unwrap();
+            create_task(&db, request).unwrap()
+        };
+
+        let old = new_task("Old work");
+        assign_task(&db, &old.id, "parser_developer", "tester", false).unwrap();
+        update_task_status(&db, &old.id, TaskStatus::InProgress, "tester", false).unwrap();
+        let next = new_task("Next");
+        assert!(matches!(
+            assign_task(&db, &next.id, "parser_developer", "tester", false),
+            Err(OperationError::AgentUnavailable(_))
+        ));
+
+        archive_task(&db, &old.id, "tester").unwrap();
+        let workload = crate::operations::metrics::get_agent_workload(&db, "parser_developer")
+            .unwrap();
+        assert_eq!(workload.current_tasks, 0);
+        assign_task(&db, &next.id, "parser_developer", "tester", false).unwrap();
+    }
+
+    #[test]
+    fn test_delete_task() {
+        let db = setup_test_db();
+        let new_task = |title: &str| {
+            let request = TaskBuilder::new()
+                .feature_id("test-feature")
+                .title(title)
+                .build()
+                .unwrap();
+            create_task(&db, request).unwrap()
+        };
+
+        let first = new_task("First");
+        let second = new_task("Second");
+        let third = new_task("Third");
+
+        // A fresh task deletes cleanly
+        delete_task(&db, &second.id, false).unwrap();
+        assert!(get_task(&db, &second.id).is_err());
+
+        // IDs are not reused after a delete
+        let fourth = new_task("Fourth");
+        assert_eq!(fourth.id, "T-test-feature-004");
+
+        // Not even when the newest task is the one deleted
+        delete_task(&db, &fourth.id, false).unwrap();
+        assert_eq!(new_task("Fifth").id, "T-test-feature-005");
+
+        // Dependencies and history need --force
+        add_task_dependency(&db, &third.id, &first.id).unwrap();
+        update_task_status(&db, &first.id, TaskStatus::InProgress, "tester", false).unwrap();
+        assert!(matches!(
+            delete_task(&db, &first.id, false),
+            Err(OperationError::Dependency(_))
+        ));
+
+        delete_task(&db, &first.id, true).unwrap();
+        assert!(get_task(&db, &first.id).is_err());
+        assert!(get_task_dependencies(&db, &third.id).unwrap().is_empty());
+        assert!(get_task_history(&db, &first.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_delete_task_with_checklist() {
+        let db = setup_test_db();
+        let request = TaskBuilder::new()
+            .feature_id("test-feature")
+            .title("Checked")
+            .build()
+            .unwrap();
+        let task = create_task(&db, request).unwrap();
+        crate::operations::checklists::add_checklist_item(&db, &task.id, "Write tests").unwrap();
+
+        let err = delete_task(&db, &task.id, false).unwrap_err();
+        assert!(matches!(err, OperationError::Dependency(_)));
+        assert!(err.to_string().contains("checklist items: 1"));
+
+        delete_task(&db, &task.id, true).unwrap();
+        assert!(get_task(&db, &task.id).is_err());
+    }
 }
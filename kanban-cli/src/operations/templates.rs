@@ -0,0 +1,358 @@
+//! Task template operations
+
+use rusqlite::{params, Row};
+
+use crate::db::Database;
+use crate::models::{Feature, Task, TaskBuilder, TaskTemplate};
+
+use super::features::{generate_feature_id, get_feature};
+use super::labels::{add_task_label, normalize_label};
+use super::tasks::create_task;
+use super::{OperationError, Result};
+
+/// Parse a template from a database row
+fn template_from_row(row: &Row) -> rusqlite::Result<TaskTemplate> {
+    let names = |column: &str| -> rusqlite::Result<Vec<String>> {
+        let json: String = row.get(column)?;
+        Ok(serde_json::from_str(&json).unwrap_or_default())
+    };
+
+    Ok(TaskTemplate {
+        name: row.get("name")?,
+        title_pattern: row.get("title_pattern")?,
+        description: row.get("description")?,
+        estimated_hours: row.get("estimated_hours")?,
+        priority: row.get("priority")?,
+        labels: names("labels")?,
+        depends_on: names("depends_on")?,
+    })
+}
+
+/// Create a template
+///
+/// The name is turned into a slug ("Code Review" becomes "code-review") and
+/// labels are normalized. Templates named in `depends_on` must already exist.
+pub fn create_template(db: &Database, template: TaskTemplate) -> Result<TaskTemplate> {
+    let name = generate_feature_id(&template.name);
+    if name.is_empty() {
+        return Err(OperationError::Validation(
+            "Template name cannot be empty".to_string(),
+        ));
+    }
+    if template.title_pattern.trim().is_empty() {
+        return Err(OperationError::Validation(
+            "Template title cannot be empty".to_string(),
+        ));
+    }
+    if get_template(db, &name).is_ok() {
+        return Err(OperationError::Validation(format!(
+            "Template '{}' already exists",
+            name
+        )));
+    }
+
+    let labels = template
+        .labels
+        .iter()
+        .map(|l| normalize_label(l))
+        .collect::<Result<Vec<_>>>()?;
+    let mut depends_on = Vec::new();
+    for dep in &template.depends_on {
+        let dep = generate_feature_id(dep);
+        if dep == name {
+            return Err(OperationError::Dependency(format!(
+                "Template '{}' cannot depend on itself",
+                name
+            )));
+        }
+        get_template(db, &dep)?;
+        depends_on.push(dep);
+    }
+
+    db.conn().execute(
+        r#"
+        INSERT INTO task_templates (name, title_pattern, description, estimated_hours, priority, labels, depends_on)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#,
+        params![
+            name,
+            template.title_pattern.trim(),
+            template.description,
+            template.estimated_hours,
+            template.priority,
+            serde_json::to_string(&labels).unwrap(),
+            serde_json::to_string(&depends_on).unwrap(),
+        ],
+    )?;
+
+    get_template(db, &name)
+}
+
+/// Get a template by name
+pub fn get_template(db: &Database, name: &str) -> Result<TaskTemplate> {
+    db.conn()
+        .query_row(
+            "SELECT * FROM task_templates WHERE name = ?",
+            params![name],
+            template_from_row,
+        )
+        .map_err(|e| match e {
+            rusqlite::Error::QueryReturnedNoRows => {
+                OperationError::NotFound(format!("Template not found: {}", name))
+            }
+            e => OperationError::Database(e),
+        })
+}
+
+/// List templates by name
+pub fn list_templates(db: &Database) -> Result<Vec<TaskTemplate>> {
+    let mut stmt = db
+        .conn()
+        .prepare("SELECT * FROM task_templates ORDER BY name")?;
+    let templates = stmt
+        .query_map([], template_from_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(templates)
+}
+
+/// Delete a template; tasks already created from it are kept
+///
+/// Refused while other templates name it in `depends_on`.
+pub fn delete_template(db: &Database, name: &str) -> Result<()> {
+    let dependents = list_templates(db)?
+        .into_iter()
+        .filter(|t| t.depends_on.iter().any(|dep| dep == name))
+        .map(|t| t.name)
+        .collect::<Vec<_>>();
+    if !dependents.is_empty() {
+        return Err(OperationError::Dependency(format!(
+            "Template '{}' is a dependency of: {}",
+            name,
+            dependents.join(", ")
+        )));
+    }
+
+    let removed = db
+        .conn()
+        .execute("DELETE FROM task_templates WHERE name = ?", params![name])?;
+    if removed == 0 {
+        return Err(OperationError::NotFound(format!(
+            "Template not found: {}",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Fill in `{title}`, `{feature}`, and `{feature_id}` placeholders
+fn render(pattern: &str, title: Option<&str>, feature: &Feature) -> String {
+    pattern
+        .replace("{title}", title.unwrap_or(""))
+        .replace("{feature}", &feature.name)
+        .replace("{feature_id}", &feature.id)
+}
+
+/// Start a task builder from a template
+///
+/// An explicit `title` replaces the title pattern unless the pattern embeds
+/// it with `{title}`. Dependencies point at the feature's unarchived tasks
+/// created from the templates in `depends_on`. Further builder calls
+/// override the template's values.
+pub fn template_builder(
+    db: &Database,
+    template: &TaskTemplate,
+    feature_id: &str,
+    title: Option<&str>,
+) -> Result<TaskBuilder> {
+    let feature = get_feature(db, feature_id)?;
+
+    let embeds_title = template.title_pattern.contains("{title}");
+    let title = match title {
+        Some(title) if !embeds_title => title.to_string(),
+        None if embeds_title => {
+            return Err(OperationError::Validation(format!(
+                "Template '{}' needs a title for its pattern '{}'",
+                template.name, template.title_pattern
+            )));
+        }
+        title => render(&template.title_pattern, title, &feature),
+    };
+
+    let mut builder = TaskBuilder::new().feature_id(feature_id).title(title);
+    if let Some(desc) = &template.description {
+        builder = builder.description(render(desc, None, &feature));
+    }
+    if let Some(priority) = template.priority {
+        builder = builder.priority(priority);
+    }
+    if let Some(hours) = template.estimated_hours {
+        builder = builder.estimated_hours(hours);
+    }
+
+    for dep in &template.depends_on {
+        let mut stmt = db.conn().prepare(
+            r#"
+            SELECT tt.task_id FROM template_tasks tt
+            JOIN tasks t ON tt.task_id = t.id
+            WHERE tt.template_name = ? AND t.feature_id = ?
+              AND t.id NOT IN (SELECT task_id FROM archived_tasks)
+            ORDER BY t.created_at
+            "#,
+        )?;
+        let task_ids = stmt
+            .query_map(params![dep, feature_id], |row| row.get::<_, String>(0))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        for task_id in task_ids {
+            builder = builder.depends_on(task_id);
+        }
+    }
+
+    Ok(builder)
+}
+
+/// Create a task from a builder started with `template_builder`
+///
+/// Attaches the template's labels plus any extra `labels` (already
+/// normalized) and remembers which template the task came from, all in one
+/// transaction.
+pub fn create_task_from_template(
+    db: &Database,
+    template: &TaskTemplate,
+    builder: TaskBuilder,
+    labels: &[String],
+    created_by: &str,
+) -> Result<Task> {
+    let request = builder
+        .build()
+        .map_err(|e| OperationError::Validation(e.to_string()))?;
+
+    let tx = db.conn().unchecked_transaction()?;
+    let task = create_task(db, request)?;
+    for label in template.labels.iter().chain(labels) {
+        add_task_label(db, &task.id, label, created_by)?;
+    }
+    db.conn().execute(
+        "INSERT INTO template_tasks (task_id, template_name) VALUES (?, ?)",
+        params![task.id, template.name],
+    )?;
+    tx.commit()?;
+
+    Ok(task)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CreateFeatureRequest;
+    use crate::operations::{features, labels, tasks};
+
+    fn setup_test_db() -> Database {
+        let db = Database::in_memory().unwrap();
+
+        features::create_feature(
+            &db,
+            CreateFeatureRequest {
+                name: "Test Feature".to_string(),
+                description: None,
+                color: None,
+            },
+        )
+        .unwrap();
+
+        db
+    }
+
+    fn template(name: &str, title_pattern: &str) -> TaskTemplate {
+        TaskTemplate {
+            name: name.to_string(),
+            title_pattern: title_pattern.to_string(),
+            description: None,
+            estimated_hours: None,
+            priority: None,
+            labels: Vec::new(),
+            depends_on: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_create_template() {
+        let db = setup_test_db();
+
+        let mut implement = template("Implement", "Implement {title}");
+        implement.labels = vec!["#Feature-Work".to_string()];
+        let implement = create_template(&db, implement).unwrap();
+        assert_eq!(implement.labels, vec!["feature-work"]);
+
+        let mut review = template("Code Review", "Code review for {feature}");
+        review.depends_on = vec!["implement".to_string()];
+        let review = create_template(&db, review).unwrap();
+        assert_eq!(review.name, "code-review");
+        assert_eq!(review.depends_on, vec!["implement"]);
+
+        assert!(create_template(&db, template("implement", "Again")).is_err());
+        let mut dangling = template("docs", "Docs");
+        dangling.depends_on = vec!["missing".to_string()];
+        assert!(matches!(
+            create_template(&db, dangling),
+            Err(OperationError::NotFound(_))
+        ));
+        assert_eq!(list_templates(&db).unwrap().len(), 2);
+
+        // Code review still depends on implement
+        assert!(matches!(
+            delete_template(&db, "implement"),
+            Err(OperationError::Dependency(_))
+        ));
+        delete_template(&db, "code-review").unwrap();
+        delete_template(&db, "implement").unwrap();
+        assert!(matches!(
+            delete_template(&db, "implement"),
+            Err(OperationError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_create_task_from_template() {
+        let db = setup_test_db();
+
+        let mut implement = template("implement", "Implement {title}");
+        implement.estimated_hours = Some(6.0);
+        let implement = create_template(&db, implement).unwrap();
+
+        let mut review = template("code-review", "Code review for {feature}");
+        review.description = Some("Checklist for {feature_id}".to_string());
+        review.priority = Some(20);
+        review.labels = vec!["review".to_string()];
+        review.depends_on = vec!["implement".to_string()];
+        let review = create_template(&db, review).unwrap();
+
+        // {title} patterns need a title
+        assert!(template_builder(&db, &implement, "test-feature", None).is_err());
+
+        let first = template_builder(&db, &implement, "test-feature", Some("parser")).unwrap();
+        let first = create_task_from_template(&db, &implement, first, &[], "test").unwrap();
+        assert_eq!(first.title, "Implement parser");
+        assert_eq!(first.estimated_hours, Some(6.0));
+
+        let builder = template_builder(&db, &review, "test-feature", None).unwrap();
+        assert_eq!(builder.dependencies(), std::slice::from_ref(&first.id));
+        let extra = vec!["urgent".to_string()];
+        let task = create_task_from_template(&db, &review, builder.priority(5), &extra, "test")
+            .unwrap();
+        assert_eq!(task.title, "Code review for Test Feature");
+        assert_eq!(task.description.as_deref(), Some("Checklist for test-feature"));
+        assert_eq!(task.priority, 5);
+        assert_eq!(
+            labels::get_task_labels(&db, &task.id).unwrap(),
+            vec!["review", "urgent"]
+        );
+        assert_eq!(tasks::get_task_dependencies(&db, &task.id).unwrap().len(), 1);
+
+        // An explicit title replaces a pattern without {title}
+        let request = template_builder(&db, &review, "test-feature", Some("Final review"))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(request.title, "Final review");
+    }
+}
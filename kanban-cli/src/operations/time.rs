@@ -0,0 +1,267 @@
+//! Time tracking operations
+
+use chrono::Utc;
+use rusqlite::{params, OptionalExtension, Row};
+
+use crate::db::Database;
+use crate::models::TimeEntry;
+
+use super::tasks::{add_actual_hours, get_task, last_id_number, record_id_number};
+use super::{OperationError, Result};
+
+/// Parse a time entry from a database row
+fn time_entry_from_row(row: &Row) -> rusqlite::Result<TimeEntry> {
+    Ok(TimeEntry {
+        id: row.get("id")?,
+        task_id: row.get("task_id")?,
+        started_at: parse_datetime(row.get::<_, String>("started_at")?),
+        ended_at: row
+            .get::<_, Option<String>>("ended_at")?
+            .map(parse_datetime),
+        hours: row.get("hours")?,
+        logged_by: row.get("logged_by")?,
+    })
+}
+
+fn parse_datetime(s: String) -> chrono::DateTime<Utc> {
+    chrono::DateTime::parse_from_rfc3339(&s)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|_| {
+            chrono::NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S")
+                .map(|dt| dt.and_utc())
+                .unwrap_or_else(|_| Utc::now())
+        })
+}
+
+/// Prefix shared by all time entry IDs
+const TIME_ENTRY_PREFIX: &str = "TE-";
+
+/// Generate a time entry ID
+///
+/// Continues from the last number handed out, so IDs are not reused after a
+/// task (and its entries) is deleted. Existing entries are scanned too, for
+/// boards created before numbers were recorded.
+pub fn generate_time_entry_id(db: &Database) -> Result<String> {
+    let max: Option<u32> = db.conn().query_row(
+        "SELECT MAX(CAST(SUBSTR(id, 4) AS INTEGER)) FROM time_entries",
+        [],
+        |row| row.get(0),
+    )?;
+    let last = max
+        .unwrap_or(0)
+        .max(last_id_number(db, TIME_ENTRY_PREFIX)?);
+    Ok(format!("{}{:03}", TIME_ENTRY_PREFIX, last + 1))
+}
+
+/// Get a time entry by ID
+pub fn get_time_entry(db: &Database, entry_id: &str) -> Result<TimeEntry> {
+    db.conn()
+        .query_row(
+            "SELECT * FROM time_entries WHERE id = ?",
+            params![entry_id],
+            time_entry_from_row,
+        )
+        .map_err(|e| match e {
+            rusqlite::Error::QueryReturnedNoRows => {
+                OperationError::NotFound(format!("Time entry {} not found", entry_id))
+            }
+            e => OperationError::Database(e),
+        })
+}
+
+/// Get all time entries for a task, oldest first
+pub fn get_time_entries(db: &Database, task_id: &str) -> Result<Vec<TimeEntry>> {
+    let mut stmt = db
+        .conn()
+        .prepare("SELECT * FROM time_entries WHERE task_id = ? ORDER BY started_at, id")?;
+    let entries = stmt
+        .query_map(params![task_id], time_entry_from_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(entries)
+}
+
+/// Get the running timer for a task, if any
+pub fn get_running_timer(db: &Database, task_id: &str) -> Result<Option<TimeEntry>> {
+    Ok(db
+        .conn()
+        .query_row(
+            "SELECT * FROM time_entries WHERE task_id = ? AND ended_at IS NULL",
+            params![task_id],
+            time_entry_from_row,
+        )
+        .optional()?)
+}
+
+/// Log hours spent on a task and add them to its actual hours
+pub fn log_time(db: &Database, task_id: &str, hours: f64, logged_by: &str) -> Result<TimeEntry> {
+    if !(hours.is_finite() && hours > 0.0) {
+        return Err(OperationError::Validation(
+            "Hours must be greater than zero".to_string(),
+        ));
+    }
+    get_task(db, task_id)?;
+
+    let entry_id = generate_time_entry_id(db)?;
+    let now = Utc::now().to_rfc3339();
+
+    let tx = db.conn().unchecked_transaction()?;
+    db.conn().execute(
+        r#"
+        INSERT INTO time_entries (id, task_id, started_at, ended_at, hours, logged_by)
+        VALUES (?, ?, ?, ?, ?, ?)
+        "#,
+        params![entry_id, task_id, now, now, hours, logged_by],
+    )?;
+    record_id_number(db, TIME_ENTRY_PREFIX, &entry_id)?;
+    add_actual_hours(db, task_id, hours, logged_by)?;
+    tx.commit()?;
+
+    get_time_entry(db, &entry_id)
+}
+
+/// Start a work timer on a task
+pub fn start_timer(db: &Database, task_id: &str, started_by: &str) -> Result<TimeEntry> {
+    get_task(db, task_id)?;
+
+    if let Some(running) = get_running_timer(db, task_id)? {
+        return Err(OperationError::Validation(format!(
+            "A timer is already running on {} (started {})",
+            task_id,
+            running.started_at.format("%Y-%m-%d %H:%M")
+        )));
+    }
+
+    let entry_id = generate_time_entry_id(db)?;
+    let now = Utc::now().to_rfc3339();
+
+    let tx = db.conn().unchecked_transaction()?;
+    db.conn().execute(
+        "INSERT INTO time_entries (id, task_id, started_at, logged_by) VALUES (?, ?, ?, ?)",
+        params![entry_id, task_id, now, started_by],
+    )?;
+    record_id_number(db, TIME_ENTRY_PREFIX, &entry_id)?;
+    tx.commit()?;
+
+    get_time_entry(db, &entry_id)
+}
+
+/// Stop the running timer on a task and add the elapsed time to its actual hours
+pub fn stop_timer(db: &Database, task_id: &str, stopped_by: &str) -> Result<TimeEntry> {
+    let running = get_running_timer(db, task_id)?.ok_or_else(|| {
+        OperationError::Validation(format!("No timer is running on {}", task_id))
+    })?;
+
+    let now = Utc::now();
+    let seconds = (now - running.started_at).num_seconds().max(0);
+    // Round to the nearest hundredth of an hour (36 seconds)
+    let hours = (seconds as f64 / 36.0).round() / 100.0;
+
+    let tx = db.conn().unchecked_transaction()?;
+    db.conn().execute(
+        "UPDATE time_entries SET ended_at = ?, hours = ? WHERE id = ?",
+        params![now.to_rfc3339(), hours, running.id],
+    )?;
+    // A timer stopped within a few seconds adds nothing worth recording
+    if hours > 0.0 {
+        add_actual_hours(db, task_id, hours, stopped_by)?;
+    }
+    tx.commit()?;
+
+    get_time_entry(db, &running.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CreateFeatureRequest, TaskBuilder};
+    use crate::operations::{features, tasks};
+
+    fn setup_test_db() -> (Database, String) {
+        let db = Database::in_memory().unwrap();
+
+        features::create_feature(
+            &db,
+            CreateFeatureRequest {
+                name: "Test Feature".to_string(),
+                description: None,
+                color: None,
+            },
+        )
+        .unwrap();
+
+        let request = TaskBuilder::new()
+            .feature_id("test-feature")
+            .title("Test task")
+            .build()
+            .unwrap();
+        let task = tasks::create_task(&db, request).unwrap();
+
+        (db, task.id)
+    }
+
+    #[test]
+    fn test_log_time() {
+        let (db, task_id) = setup_test_db();
+
+        log_time(&db, &task_id, 2.5, "dev").unwrap();
+        let entry = log_time(&db, &task_id, 1.0, "dev").unwrap();
+        assert_eq!(entry.id, "TE-002");
+        assert_eq!(entry.hours, Some(1.0));
+
+        let task = tasks::get_task(&db, &task_id).unwrap();
+        assert_eq!(task.actual_hours, Some(3.5));
+        assert_eq!(get_time_entries(&db, &task_id).unwrap().len(), 2);
+
+        // The newest entry's ID is not handed out again once it is gone
+        db.conn()
+            .execute("DELETE FROM time_entries WHERE id = 'TE-002'", [])
+            .unwrap();
+        assert_eq!(generate_time_entry_id(&db).unwrap(), "TE-003");
+
+        let history = tasks::get_task_history(&db, &task_id).unwrap();
+        assert!(history.iter().any(|h| h.field_changed == "actual_hours"));
+
+        assert!(matches!(
+            log_time(&db, &task_id, 0.0, "dev"),
+            Err(OperationError::Validation(_))
+        ));
+        assert!(matches!(
+            log_time(&db, "T-missing-001", 1.0, "dev"),
+            Err(OperationError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_timer_start_stop() {
+        let (db, task_id) = setup_test_db();
+
+        assert!(matches!(
+            stop_timer(&db, &task_id, "dev"),
+            Err(OperationError::Validation(_))
+        ));
+
+        let started = start_timer(&db, &task_id, "dev").unwrap();
+        assert!(started.ended_at.is_none());
+        assert!(matches!(
+            start_timer(&db, &task_id, "dev"),
+            Err(OperationError::Validation(_))
+        ));
+
+        // Pretend the timer has been running for 90 minutes
+        let earlier = (Utc::now() - chrono::Duration::minutes(90)).to_rfc3339();
+        db.conn()
+            .execute(
+                "UPDATE time_entries SET started_at = ? WHERE id = ?",
+                params![earlier, started.id],
+            )
+            .unwrap();
+
+        let stopped = stop_timer(&db, &task_id, "dev").unwrap();
+        assert!(stopped.ended_at.is_some());
+        assert_eq!(stopped.hours, Some(1.5));
+        assert!(get_running_timer(&db, &task_id).unwrap().is_none());
+
+        let task = tasks::get_task(&db, &task_id).unwrap();
+        assert_eq!(task.actual_hours, Some(1.5));
+    }
+}
@@ -0,0 +1,288 @@
+//! Work-in-progress limit operations
+
+use rusqlite::{params, OptionalExtension, Row};
+
+use crate::db::Database;
+use crate::models::{Task, WipLimit};
+use crate::state_machine::TaskStatus;
+
+use super::{OperationError, Result};
+
+/// Parse a WIP limit from a database row
+fn wip_limit_from_row(row: &Row) -> rusqlite::Result<WipLimit> {
+    let agent_id: String = row.get("agent_id")?;
+    let column = row.as_ref().column_index("status")?;
+    let status = row.get::<_, String>(column)?.parse().map_err(|e| {
+        rusqlite::Error::FromSqlConversionFailure(column, rusqlite::types::Type::Text, Box::new(e))
+    })?;
+    Ok(WipLimit {
+        status,
+        agent_id: (!agent_id.is_empty()).then_some(agent_id),
+        max_tasks: row.get("max_tasks")?,
+    })
+}
+
+/// Set the limit for a status, for the whole column or one agent
+///
+/// Todo and Done have no limit: new work is not pulled into Todo, and
+/// finished work never needs to be pulled.
+pub fn set_wip_limit(
+    db: &Database,
+    status: TaskStatus,
+    agent_id: Option<&str>,
+    max_tasks: i64,
+) -> Result<WipLimit> {
+    if matches!(status, TaskStatus::Todo | TaskStatus::Done) {
+        return Err(OperationError::Validation(format!(
+            "WIP limits do not apply to {}",
+            status
+        )));
+    }
+    if max_tasks < 1 {
+        return Err(OperationError::Validation(
+            "WIP limit must be at least 1".to_string(),
+        ));
+    }
+    if let Some(agent_id) = agent_id {
+        db.conn()
+            .query_row("SELECT id FROM agents WHERE id = ?", params![agent_id], |_| Ok(()))
+            .map_err(|_| OperationError::NotFound(format!("Agent not found: {}", agent_id)))?;
+    }
+
+    db.conn().execute(
+        r#"
+        INSERT INTO wip_limits (status, agent_id, max_tasks) VALUES (?, ?, ?)
+        ON CONFLICT (status, agent_id) DO UPDATE SET max_tasks = excluded.max_tasks
+        "#,
+        params![status.as_str(), agent_id.unwrap_or(""), max_tasks],
+    )?;
+
+    Ok(WipLimit {
+        status,
+        agent_id: agent_id.map(str::to_string),
+        max_tasks,
+    })
+}
+
+/// Remove a limit
+pub fn clear_wip_limit(db: &Database, status: TaskStatus, agent_id: Option<&str>) -> Result<()> {
+    let removed = db.conn().execute(
+        "DELETE FROM wip_limits WHERE status = ? AND agent_id = ?",
+        params![status.as_str(), agent_id.unwrap_or("")],
+    )?;
+    if removed == 0 {
+        return Err(OperationError::NotFound(match agent_id {
+            Some(agent_id) => format!("No {} WIP limit for agent {}", status, agent_id),
+            None => format!("No {} WIP limit", status),
+        }));
+    }
+    Ok(())
+}
+
+/// Get the limit for a status, for the whole column or one agent
+pub fn get_wip_limit(
+    db: &Database,
+    status: TaskStatus,
+    agent_id: Option<&str>,
+) -> Result<Option<i64>> {
+    let limit = db
+        .conn()
+        .query_row(
+            "SELECT max_tasks FROM wip_limits WHERE status = ? AND agent_id = ?",
+            params![status.as_str(), agent_id.unwrap_or("")],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(limit)
+}
+
+/// List limits, column limits first
+pub fn list_wip_limits(db: &Database) -> Result<Vec<WipLimit>> {
+    let mut stmt = db
+        .conn()
+        .prepare("SELECT * FROM wip_limits ORDER BY agent_id != '', agent_id, status")?;
+    let limits = stmt
+        .query_map([], wip_limit_from_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(limits)
+}
+
+/// Refuse to give `agent_id` another task in `status` if that would exceed
+/// the agent's limit
+///
+/// `task_id` is the task being moved or assigned, which is not counted.
+/// Archived tasks do not count.
+pub fn check_agent_wip_limit(
+    db: &Database,
+    agent_id: &str,
+    status: TaskStatus,
+    task_id: &str,
+) -> Result<()> {
+    let Some(limit) = get_wip_limit(db, status, Some(agent_id))? else {
+        return Ok(());
+    };
+    let count: i64 = db.conn().query_row(
+        r#"
+        SELECT COUNT(*) FROM tasks
+        WHERE assigned_agent = ? AND status = ? AND id != ?
+          AND id NOT IN (SELECT task_id FROM archived_tasks)
+        "#,
+        params![agent_id, status.as_str(), task_id],
+        |row| row.get(0),
+    )?;
+    if count >= limit {
+        return Err(OperationError::WipLimit(format!(
+            "{} already has {}/{} tasks in {}",
+            agent_id, count, limit, status
+        )));
+    }
+    Ok(())
+}
+
+/// Refuse to move `task` into `status` if that would exceed a limit
+///
+/// Checks the column limit on the task's feature board, then the limit for
+/// the task's agent. Archived tasks do not count.
+pub fn check_wip_limits(db: &Database, task: &Task, status: TaskStatus) -> Result<()> {
+    if let Some(limit) = get_wip_limit(db, status, None)? {
+        let count: i64 = db.conn().query_row(
+            r#"
+            SELECT COUNT(*) FROM tasks
+            WHERE feature_id = ? AND status = ? AND id != ?
+              AND id NOT IN (SELECT task_id FROM archived_tasks)
+            "#,
+            params![task.feature_id, status.as_str(), task.id],
+            |row| row.get(0),
+        )?;
+        if count >= limit {
+            return Err(OperationError::WipLimit(format!(
+                "{} on {} already has {}/{} tasks",
+                status, task.feature_id, count, limit
+            )));
+        }
+    }
+
+    if let Some(agent_id) = &task.assigned_agent {
+        check_agent_wip_limit(db, agent_id, status, &task.id)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CreateFeatureRequest, TaskBuilder};
+    use crate::operations::{features, tasks};
+
+    fn setup_test_db() -> Database {
+        let db = Database::in_memory().unwrap();
+
+        features::create_feature(
+            &db,
+            CreateFeatureRequest {
+                name: "Test Feature".to_string(),
+                description: None,
+                color: None,
+            },
+        )
+        .unwrap();
+
+        for i in 1..=3 {
+            let request = TaskBuilder::new()
+                .feature_id("test-feature")
+                .title(format!("Task {}", i))
+                .build()
+                .unwrap();
+            tasks::create_task(&db, request).unwrap();
+        }
+
+        db
+    }
+
+    #[test]
+    fn test_wip_limits() {
+        let db = setup_test_db();
+
+        set_wip_limit(&db, TaskStatus::InProgress, None, 2).unwrap();
+        set_wip_limit(&db, TaskStatus::InProgress, Some("parser_developer"), 1).unwrap();
+        // Setting again updates the limit
+        set_wip_limit(&db, TaskStatus::InQa, None, 5).unwrap();
+        set_wip_limit(&db, TaskStatus::InQa, None, 4).unwrap();
+        assert_eq!(get_wip_limit(&db, TaskStatus::InQa, None).unwrap(), Some(4));
+
+        assert!(set_wip_limit(&db, TaskStatus::Done, None, 1).is_err());
+        assert!(set_wip_limit(&db, TaskStatus::Todo, None, 1).is_err());
+        assert!(set_wip_limit(&db, TaskStatus::Blocked, None, 0).is_err());
+        assert!(set_wip_limit(&db, TaskStatus::Blocked, Some("nobody"), 1).is_err());
+
+        let limits = list_wip_limits(&db).unwrap();
+        assert_eq!(limits.len(), 3);
+        assert!(limits[0].agent_id.is_none());
+        assert_eq!(limits[2].agent_id.as_deref(), Some("parser_developer"));
+
+        clear_wip_limit(&db, TaskStatus::InQa, None).unwrap();
+        assert!(matches!(
+            clear_wip_limit(&db, TaskStatus::InQa, None),
+            Err(OperationError::NotFound(_))
+        ));
+
+        // A stored status that does not parse is an error, not a guess
+        db.conn()
+            .execute("UPDATE wip_limits SET status = 'bogus' WHERE agent_id = ''", [])
+            .unwrap();
+        assert!(list_wip_limits(&db).is_err());
+    }
+
+    #[test]
+    fn test_status_update_enforces_limits() {
+        let db = setup_test_db();
+        set_wip_limit(&db, TaskStatus::InProgress, None, 2).unwrap();
+        set_wip_limit(&db, TaskStatus::InProgress, Some("parser_developer"), 1).unwrap();
+        let move_to = |id: &str, override_wip: bool| {
+            tasks::update_task_status(&db, id, TaskStatus::InProgress, "test", override_wip)
+        };
+
+        tasks::assign_task(&db, "T-test-feature-001", "parser_developer", "test", false).unwrap();
+        tasks::assign_task(&db, "T-test-feature-002", "parser_developer", "test", false).unwrap();
+        move_to("T-test-feature-001", false).unwrap();
+        assert!(matches!(
+            move_to("T-test-feature-002", false),
+            Err(OperationError::WipLimit(_))
+        ));
+
+        move_to("T-test-feature-003", false).unwrap();
+        // The column is now full for everyone
+        assert!(matches!(
+            move_to("T-test-feature-002", false),
+            Err(OperationError::WipLimit(_))
+        ));
+        let task = move_to("T-test-feature-002", true).unwrap();
+        assert_eq!(task.status, TaskStatus::InProgress);
+    }
+
+    #[test]
+    fn test_assign_enforces_agent_limits() {
+        let db = setup_test_db();
+        for id in ["T-test-feature-001", "T-test-feature-002"] {
+            tasks::update_task_status(&db, id, TaskStatus::InProgress, "test", false).unwrap();
+        }
+        set_wip_limit(&db, TaskStatus::InProgress, Some("parser_developer"), 1).unwrap();
+        let assign = |id: &str, override_wip: bool| {
+            tasks::assign_task(&db, id, "parser_developer", "test", override_wip)
+        };
+
+        assign("T-test-feature-001", false).unwrap();
+        // Reassigning the same task does not count it twice
+        assign("T-test-feature-001", false).unwrap();
+        assert!(matches!(
+            assign("T-test-feature-002", false),
+            Err(OperationError::WipLimit(_))
+        ));
+        // A todo task is outside the in-progress limit
+        assign("T-test-feature-003", false).unwrap();
+
+        let task = assign("T-test-feature-002", true).unwrap();
+        assert_eq!(task.assigned_agent.as_deref(), Some("parser_developer"));
+    }
+}
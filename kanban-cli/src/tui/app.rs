@@ -1,9 +1,20 @@
 //! TUI application state
 
+use std::collections::HashMap;
+
+use ratatui::layout::{Constraint, Direction, Layout, Margin, Rect};
+
 use crate::db::Database;
-use crate::models::{Blocker, Feature, Task};
-use crate::operations::{blockers, features, metrics, tasks, OperationError};
-use crate::state_machine::TaskStatus;
+use crate::models::{AgentWorkload, Blocker, ChecklistItem, Feature, Task, TaskComment};
+use crate::operations::metrics::BurndownPoint;
+use crate::operations::{
+    blockers, checklists, features, labels, metrics, tasks, wip, OperationError,
+};
+use crate::state_machine::{StateMachine, TaskStatus};
+
+use super::theme::Theme;
+use super::widgets::form::Form;
+use super::widgets::task_card::CARD_HEIGHT;
 
 /// The focused column in the kanban board
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,7 +27,7 @@ pub enum Column {
 }
 
 impl Column {
-    pub fn to_status(&self) -> TaskStatus {
+    pub fn to_status(self) -> TaskStatus {
         match self {
             Column::Todo => TaskStatus::Todo,
             Column::InProgress => TaskStatus::InProgress,
@@ -26,6 +37,16 @@ impl Column {
         }
     }
 
+    pub fn from_status(status: TaskStatus) -> Column {
+        match status {
+            TaskStatus::Todo => Column::Todo,
+            TaskStatus::InProgress => Column::InProgress,
+            TaskStatus::Blocked => Column::Blocked,
+            TaskStatus::InQa => Column::InQa,
+            TaskStatus::Done => Column::Done,
+        }
+    }
+
     pub fn next(&self) -> Column {
         match self {
             Column::Todo => Column::InProgress,
@@ -57,12 +78,201 @@ impl Column {
     }
 }
 
+/// Card filter built from the search bar query
+///
+/// Plain words match the title, description, or agent; `@agent` limits to
+/// one assigned agent, `#label` keeps tasks carrying that label, and `p<N`
+/// keeps tasks with priority below N.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaskFilter {
+    pub terms: Vec<String>,
+    pub agent: Option<String>,
+    pub labels: Vec<String>,
+    pub max_priority: Option<i32>,
+}
+
+impl TaskFilter {
+    /// Parse a search query into a filter
+    pub fn parse(query: &str) -> Self {
+        let mut filter = TaskFilter::default();
+        for token in query.split_whitespace() {
+            if let Some(agent) = token.strip_prefix('@').filter(|a| !a.is_empty()) {
+                filter.agent = Some(agent.to_lowercase());
+            } else if let Some(label) = token.strip_prefix('#').filter(|l| !l.is_empty()) {
+                filter.labels.push(label.to_lowercase());
+            } else if let Some(n) = token.strip_prefix("p<").and_then(|n| n.parse().ok()) {
+                filter.max_priority = Some(n);
+            } else {
+                filter.terms.push(token.to_lowercase());
+            }
+        }
+        filter
+    }
+
+    /// Check if the filter has no conditions
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+            && self.agent.is_none()
+            && self.labels.is_empty()
+            && self.max_priority.is_none()
+    }
+
+    /// Check if a task with the given labels passes the filter
+    pub fn matches(&self, task: &Task, labels: &[String]) -> bool {
+        let agent = task.assigned_agent.as_deref().unwrap_or("").to_lowercase();
+
+        if let Some(wanted) = &self.agent {
+            if !agent.contains(wanted.as_str()) {
+                return false;
+            }
+        }
+        if let Some(max) = self.max_priority {
+            if task.priority >= max {
+                return false;
+            }
+        }
+        if !self.labels.iter().all(|wanted| labels.contains(wanted)) {
+            return false;
+        }
+
+        let title = task.title.to_lowercase();
+        let description = task.description.as_deref().unwrap_or("").to_lowercase();
+        self.terms.iter().all(|term| {
+            title.contains(term.as_str())
+                || description.contains(term.as_str())
+                || agent.contains(term.as_str())
+        })
+    }
+}
+
+/// How the board is split into horizontal swimlanes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Swimlanes {
+    Off,
+    Agent,
+    Priority,
+}
+
+/// Upper bounds (exclusive) and labels of the priority swimlanes
+const PRIORITY_BANDS: &[(i32, &str)] = &[(25, "P0-24"), (50, "P25-49"), (100, "P50-99")];
+
+impl Swimlanes {
+    /// Cycle off -> agent -> priority -> off
+    pub fn next(self) -> Self {
+        match self {
+            Swimlanes::Off => Swimlanes::Agent,
+            Swimlanes::Agent => Swimlanes::Priority,
+            Swimlanes::Priority => Swimlanes::Off,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Swimlanes::Off => "off",
+            Swimlanes::Agent => "agent",
+            Swimlanes::Priority => "priority",
+        }
+    }
+
+    /// Sort key and label of the lane a task belongs to
+    pub fn lane_of(self, task: &Task) -> (usize, String) {
+        match self {
+            Swimlanes::Off => (0, String::new()),
+            Swimlanes::Agent => match &task.assigned_agent {
+                Some(agent) => (0, agent.clone()),
+                None => (1, "Unassigned".to_string()),
+            },
+            Swimlanes::Priority => PRIORITY_BANDS
+                .iter()
+                .position(|(max, _)| task.priority < *max)
+                .map(|i| (i, PRIORITY_BANDS[i].1.to_string()))
+                .unwrap_or((PRIORITY_BANDS.len(), "P100+".to_string())),
+        }
+    }
+}
+
+/// One swimlane within a column, as laid out on screen
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaneCell {
+    pub label: String,
+    /// Index of the lane's first card in `tasks_for_column`
+    pub start: usize,
+    /// Number of cards in the lane
+    pub len: usize,
+    /// Cards scrolled off the top of the lane
+    pub offset: usize,
+    /// Row holding the lane label
+    pub header: Rect,
+    /// Rows holding the cards
+    pub cards: Rect,
+}
+
+impl LaneCell {
+    /// Number of cards that fit in the lane
+    pub fn visible(&self) -> usize {
+        ((self.cards.height / CARD_HEIGHT) as usize).max(1)
+    }
+}
+
+/// Most recent board changes kept for undo
+const UNDO_LIMIT: usize = 50;
+
+/// A board change that `u` can reverse
+///
+/// `applied` is the value the TUI set, so undo can tell whether someone
+/// else changed the task since.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UndoAction {
+    /// A task moved from `previous` to `applied`
+    Status {
+        task_id: String,
+        previous: TaskStatus,
+        applied: TaskStatus,
+    },
+    /// A task was (re)assigned from `previous` to `applied`
+    Assignment {
+        task_id: String,
+        previous: Option<String>,
+        applied: Option<String>,
+    },
+}
+
+impl UndoAction {
+    /// Capture a task's status before moving it to `to`
+    pub fn status(task: &Task, to: TaskStatus) -> Self {
+        UndoAction::Status {
+            task_id: task.id.clone(),
+            previous: task.status,
+            applied: to,
+        }
+    }
+
+    /// Capture a task's assignment before assigning it to `agent_id`
+    pub fn assignment(task: &Task, agent_id: &str) -> Self {
+        UndoAction::Assignment {
+            task_id: task.id.clone(),
+            previous: task.assigned_agent.clone(),
+            applied: Some(agent_id.to_string()),
+        }
+    }
+}
+
 /// Current view mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ViewMode {
     Board,
     TaskDetail,
     Help,
+    CreateTask,
+    EditTask,
+    AssignAgent,
+    Search,
+    Blockers,
+    ResolveBlocker,
+    AddBlocker,
+    AddComment,
+    Burndown,
+    DependencyGraph,
 }
 
 /// Application state for the TUI
@@ -76,6 +286,15 @@ pub struct App {
     /// Active blockers
     pub blockers: Vec<Blocker>,
 
+    /// Labels of the tasks in the current feature, keyed by task ID
+    pub task_labels: HashMap<String, Vec<String>>,
+
+    /// Checklists of the tasks in the current feature, keyed by task ID
+    pub checklists: HashMap<String, Vec<ChecklistItem>>,
+
+    /// Column WIP limits, indexed by `Column as usize`
+    pub wip_limits: [Option<i64>; 5],
+
     /// Currently selected column
     pub selected_column: Column,
 
@@ -90,6 +309,66 @@ pub struct App {
 
     /// Feature metrics summary
     pub metrics_summary: Option<String>,
+
+    /// Active input form (when a form view is open)
+    pub form: Option<Form>,
+
+    /// View to return to when the active form closes
+    pub form_return_view: ViewMode,
+
+    /// Agents offered by the assignment picker
+    pub agent_choices: Vec<AgentWorkload>,
+
+    /// Selected index in the assignment picker
+    pub agent_choice_index: usize,
+
+    /// Raw text typed into the search bar
+    pub search_query: String,
+
+    /// Filter applied to the visible cards
+    pub filter: TaskFilter,
+
+    /// Selected index in the blockers panel
+    pub selected_blocker_index: usize,
+
+    /// Comments on the task shown in the detail view
+    pub comments: Vec<TaskComment>,
+
+    /// Screen area of each column from the last draw (for mouse hit-testing)
+    pub column_areas: Vec<(Column, Rect)>,
+
+    /// Swimlane grouping of the board
+    pub swimlanes: Swimlanes,
+
+    /// First visible card in each column, indexed by `Column as usize`
+    pub column_scroll: [usize; 5],
+
+    /// Task being dragged with the mouse
+    pub drag_task_id: Option<String>,
+
+    /// Colors used for drawing
+    pub theme: Theme,
+
+    /// Agent the user works as (from config), for the "my tasks" filter
+    pub my_agent: Option<String>,
+
+    /// Burndown data for the current feature (loaded when the chart opens)
+    pub burndown: Vec<BurndownPoint>,
+
+    /// Dependency edges for the current feature (loaded when the graph opens)
+    pub dependency_edges: Vec<(String, String)>,
+
+    /// Dependencies on tasks in other features, as (task_id, upstream task)
+    pub external_dependencies: Vec<(String, Task)>,
+
+    /// Vertical scroll offset of the dependency graph
+    pub graph_scroll: u16,
+
+    /// Changes that can be undone, most recent last
+    pub undo_stack: Vec<UndoAction>,
+
+    /// SQLite data_version seen at the last refresh check
+    pub data_version: i64,
 }
 
 impl App {
@@ -99,11 +378,34 @@ impl App {
             current_feature: None,
             tasks: Vec::new(),
             blockers: Vec::new(),
+            task_labels: HashMap::new(),
+            checklists: HashMap::new(),
+            wip_limits: [None; 5],
             selected_column: Column::Todo,
             selected_task_index: 0,
             view_mode: ViewMode::Board,
             status_message: None,
             metrics_summary: None,
+            form: None,
+            form_return_view: ViewMode::Board,
+            agent_choices: Vec::new(),
+            agent_choice_index: 0,
+            search_query: String::new(),
+            filter: TaskFilter::default(),
+            selected_blocker_index: 0,
+            comments: Vec::new(),
+            column_areas: Vec::new(),
+            swimlanes: Swimlanes::Off,
+            column_scroll: [0; 5],
+            drag_task_id: None,
+            theme: Theme::default(),
+            my_agent: None,
+            burndown: Vec::new(),
+            dependency_edges: Vec::new(),
+            external_dependencies: Vec::new(),
+            graph_scroll: 0,
+            undo_stack: Vec::new(),
+            data_version: db.data_version()?,
         };
 
         // Load the first active feature if any
@@ -127,7 +429,12 @@ impl App {
     /// Refresh task list
     pub fn refresh_tasks(&mut self, db: &Database) -> Result<(), OperationError> {
         if let Some(feature) = &self.current_feature {
-            self.tasks = tasks::list_tasks(db, Some(&feature.id), None, None)?;
+            self.tasks = tasks::list_tasks(db, Some(&feature.id), None, None, &[])?;
+            self.task_labels = labels::get_feature_labels(db, &feature.id)?;
+            self.checklists = checklists::get_feature_checklists(db, &feature.id)?;
+        }
+        for &column in Column::all() {
+            self.wip_limits[column as usize] = wip::get_wip_limit(db, column.to_status(), None)?;
         }
         Ok(())
     }
@@ -137,6 +444,124 @@ impl App {
         if let Some(feature) = &self.current_feature {
             self.blockers = blockers::list_active_blockers(db, Some(&feature.id))?;
         }
+        self.selected_blocker_index = self
+            .selected_blocker_index
+            .min(self.blockers.len().saturating_sub(1));
+        Ok(())
+    }
+
+    /// Reload everything shown on the board
+    pub fn refresh_all(&mut self, db: &Database) -> Result<(), OperationError> {
+        self.refresh_tasks(db)?;
+        self.refresh_blockers(db)?;
+        self.update_metrics(db)?;
+        Ok(())
+    }
+
+    /// Reload the board if another process changed the database since the last check
+    ///
+    /// Returns true when the board was reloaded. The selected task and blocker
+    /// stay selected. A resolve form whose blocker is no longer active is
+    /// closed so it cannot act on a different blocker.
+    pub fn refresh_if_changed(&mut self, db: &Database) -> Result<bool, OperationError> {
+        let version = db.data_version()?;
+        if version == self.data_version {
+            return Ok(false);
+        }
+
+        let selected = self.selected_task().map(|t| t.id.clone());
+        let selected_blocker = self.selected_blocker().map(|b| b.id.clone());
+        if self.current_feature.is_none() {
+            if let Some(feature) = features::list_features(db, None)?.first() {
+                self.load_feature(db, &feature.id)?;
+            }
+        } else {
+            self.refresh_all(db)?;
+        }
+
+        if let Some(task_id) = selected {
+            self.select_task_by_id(&task_id);
+            if self.view_mode == ViewMode::TaskDetail {
+                self.comments = tasks::get_task_comments(db, &task_id)?;
+            }
+        }
+        if let Some(blocker_id) = selected_blocker {
+            if !self.select_blocker_by_id(&blocker_id) && self.view_mode == ViewMode::ResolveBlocker
+            {
+                self.close_form();
+                self.set_status(format!("Blocker {} is no longer active", blocker_id));
+            }
+        }
+        self.clamp_selection();
+        self.data_version = version;
+        Ok(true)
+    }
+
+    /// Remember a change so it can be undone
+    pub fn push_undo(&mut self, action: UndoAction) {
+        if self.undo_stack.len() == UNDO_LIMIT {
+            self.undo_stack.remove(0);
+        }
+        self.undo_stack.push(action);
+    }
+
+    /// Reverse the most recent status or assignment change
+    ///
+    /// Moves go back through a normal transition when the state machine
+    /// allows it and are forced back otherwise. If the task was changed
+    /// outside the TUI since, the entry is dropped instead.
+    pub fn undo(&mut self, db: &Database) -> Result<(), OperationError> {
+        let Some(action) = self.undo_stack.pop() else {
+            self.set_status("Nothing to undo");
+            return Ok(());
+        };
+
+        let (task_id, message) = match action {
+            UndoAction::Status {
+                task_id,
+                previous,
+                applied,
+            } => {
+                let current = tasks::get_task(db, &task_id)?.status;
+                if current != applied {
+                    self.set_status(format!(
+                        "Cannot undo: {} was moved to {} since",
+                        task_id, current
+                    ));
+                    return Ok(());
+                }
+                // Undoing a move puts the task back where it was, so WIP
+                // limits do not apply
+                if StateMachine::can_transition(&current, &previous) {
+                    tasks::update_task_status(db, &task_id, previous, "tui", true)?;
+                } else {
+                    tasks::revert_task_status(db, &task_id, previous, "tui")?;
+                }
+                let message = format!("Undo: moved {} back to {}", task_id, previous);
+                (task_id, message)
+            }
+            UndoAction::Assignment {
+                task_id,
+                previous,
+                applied,
+            } => {
+                let current = tasks::get_task(db, &task_id)?.assigned_agent;
+                if current != applied {
+                    self.set_status(format!("Cannot undo: {} was reassigned since", task_id));
+                    return Ok(());
+                }
+                tasks::revert_task_assignment(db, &task_id, previous.as_deref(), "tui")?;
+                let message = match &previous {
+                    Some(agent) => format!("Undo: {} reassigned to {}", task_id, agent),
+                    None => format!("Undo: {} unassigned", task_id),
+                };
+                (task_id, message)
+            }
+        };
+
+        self.refresh_all(db)?;
+        self.select_task_by_id(&task_id);
+        self.set_status(message);
         Ok(())
     }
 
@@ -157,20 +582,179 @@ impl App {
     }
 
     /// Get tasks for a specific column
+    ///
+    /// With swimlanes on, cards are grouped by lane so selection moves lane by lane.
     pub fn tasks_for_column(&self, column: &Column) -> Vec<&Task> {
         let status = column.to_status();
-        self.tasks
+        let mut tasks: Vec<&Task> = self
+            .tasks
             .iter()
-            .filter(|t| t.status == status)
+            .filter(|t| t.status == status && self.filter.matches(t, self.labels_of(&t.id)))
+            .collect();
+        if self.swimlanes != Swimlanes::Off {
+            tasks.sort_by_cached_key(|t| self.swimlanes.lane_of(t));
+        }
+        tasks
+    }
+
+    /// Labels of the swimlanes shown on the board, in display order
+    pub fn lanes(&self) -> Vec<String> {
+        if self.swimlanes == Swimlanes::Off {
+            return Vec::new();
+        }
+        let mut lanes: Vec<(usize, String)> = self
+            .tasks
+            .iter()
+            .filter(|t| self.filter.matches(t, self.labels_of(&t.id)))
+            .map(|t| self.swimlanes.lane_of(t))
+            .collect();
+        lanes.sort();
+        lanes.dedup();
+        lanes.into_iter().map(|(_, label)| label).collect()
+    }
+
+    /// Switch to the next swimlane mode
+    pub fn cycle_swimlanes(&mut self) {
+        let selected = self.selected_task().map(|t| t.id.clone());
+        self.swimlanes = self.swimlanes.next();
+        if let Some(task_id) = selected {
+            self.select_task_by_id(&task_id);
+        }
+        self.set_status(format!("Swimlanes: {}", self.swimlanes.label()));
+    }
+
+    /// Lay out a column's swimlanes inside the column area from the last draw
+    ///
+    /// Every column gets the same lanes at the same rows so they line up
+    /// across the board. Only the lane holding the selection is scrolled.
+    pub fn lane_cells(&self, column: Column) -> Vec<LaneCell> {
+        let lanes = self.lanes();
+        let Some((_, area)) = self.column_areas.iter().find(|(c, _)| *c == column) else {
+            return Vec::new();
+        };
+        if lanes.is_empty() {
+            return Vec::new();
+        }
+
+        let inner = area.inner(Margin::new(1, 1));
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Ratio(1, lanes.len() as u32); lanes.len()])
+            .split(inner);
+
+        let tasks = self.tasks_for_column(&column);
+        let mut start = 0;
+        lanes
+            .into_iter()
+            .zip(rows.iter())
+            .map(|(label, row)| {
+                let len = tasks[start..]
+                    .iter()
+                    .take_while(|t| self.swimlanes.lane_of(t).1 == label)
+                    .count();
+                let mut cell = LaneCell {
+                    label,
+                    start,
+                    len,
+                    offset: 0,
+                    header: Rect { height: row.height.min(1), ..*row },
+                    cards: Rect {
+                        y: row.y + row.height.min(1),
+                        height: row.height.saturating_sub(1),
+                        ..*row
+                    },
+                };
+
+                let selected = self.selected_task_index;
+                if column == self.selected_column && (start..start + len).contains(&selected) {
+                    cell.offset = (selected - start + 1).saturating_sub(cell.visible());
+                }
+                start += len;
+                cell
+            })
             .collect()
     }
 
+    /// Apply a search query to the board
+    pub fn set_search_query(&mut self, query: String) {
+        self.filter = TaskFilter::parse(&query);
+        self.search_query = query;
+        self.clamp_selection();
+    }
+
+    /// Toggle the quick filter for the configured agent's tasks
+    pub fn toggle_agent_filter(&mut self) {
+        if self.filter.agent.is_some() {
+            self.filter.agent = None;
+        } else if let Some(agent) = &self.my_agent {
+            self.filter.agent = Some(agent.to_lowercase());
+        } else {
+            self.set_status("Set \"agent\" in the config file to filter to your tasks");
+        }
+        self.clamp_selection();
+    }
+
+    /// Remove all card filters
+    pub fn clear_filter(&mut self) {
+        self.search_query.clear();
+        self.filter = TaskFilter::default();
+        self.clamp_selection();
+    }
+
+    /// Keep the selection inside the visible cards of the column
+    pub fn clamp_selection(&mut self) {
+        let count = self.tasks_for_column(&self.selected_column).len();
+        self.selected_task_index = self.selected_task_index.min(count.saturating_sub(1));
+    }
+
     /// Get the currently selected task
     pub fn selected_task(&self) -> Option<&Task> {
         let column_tasks = self.tasks_for_column(&self.selected_column);
         column_tasks.get(self.selected_task_index).copied()
     }
 
+    /// Select a task by ID, following it to its current column
+    pub fn select_task_by_id(&mut self, task_id: &str) {
+        let Some(task) = self.tasks.iter().find(|t| t.id == task_id) else {
+            return;
+        };
+        let column = Column::from_status(task.status);
+
+        if let Some(index) = self
+            .tasks_for_column(&column)
+            .iter()
+            .position(|t| t.id == task_id)
+        {
+            self.selected_column = column;
+            self.selected_task_index = index;
+        }
+    }
+
+    /// Find the column and card index under a screen position
+    pub fn hit_test(&self, x: u16, y: u16, card_height: u16) -> Option<(Column, Option<usize>)> {
+        let (column, area) = self.column_areas.iter().find(|(_, area)| {
+            x >= area.x && x < area.x + area.width && y >= area.y && y < area.y + area.height
+        })?;
+
+        if self.swimlanes != Swimlanes::Off {
+            let card = self.lane_cells(*column).into_iter().find_map(|cell| {
+                let row = y.checked_sub(cell.cards.y)?;
+                if row >= cell.cards.height {
+                    return None;
+                }
+                let index = cell.offset + (row / card_height) as usize;
+                (index < cell.len).then_some(cell.start + index)
+            });
+            return Some((*column, card));
+        }
+
+        // Cards start inside the top border, below any scrolled-off cards
+        let row = y.checked_sub(area.y + 1)?;
+        let index = self.column_scroll[*column as usize] + (row / card_height) as usize;
+        let card = (index < self.tasks_for_column(column).len()).then_some(index);
+        Some((*column, card))
+    }
+
     /// Move selection up in current column
     pub fn select_up(&mut self) {
         if self.selected_task_index > 0 {
@@ -186,6 +770,48 @@ impl App {
         }
     }
 
+    /// Move selection down by one screen of cards
+    pub fn page_down(&mut self) {
+        let count = self.tasks_for_column(&self.selected_column).len();
+        let page = self.visible_cards(self.selected_column);
+        self.selected_task_index = (self.selected_task_index + page).min(count.saturating_sub(1));
+    }
+
+    /// Move selection up by one screen of cards
+    pub fn page_up(&mut self) {
+        let page = self.visible_cards(self.selected_column);
+        self.selected_task_index = self.selected_task_index.saturating_sub(page);
+    }
+
+    /// Number of cards that fit in a column, based on the last draw
+    pub fn visible_cards(&self, column: Column) -> usize {
+        self.column_areas
+            .iter()
+            .find(|(c, _)| *c == column)
+            .map(|(_, area)| (area.height.saturating_sub(2) / CARD_HEIGHT) as usize)
+            .unwrap_or(0)
+            .max(1)
+    }
+
+    /// Keep column scroll offsets in range and the selected card in view
+    pub fn update_scroll(&mut self) {
+        for &column in Column::all() {
+            let count = self.tasks_for_column(&column).len();
+            let visible = self.visible_cards(column);
+            let mut offset = self.column_scroll[column as usize].min(count.saturating_sub(visible));
+
+            if column == self.selected_column {
+                let selected = self.selected_task_index;
+                if selected < offset {
+                    offset = selected;
+                } else if selected >= offset + visible {
+                    offset = selected + 1 - visible;
+                }
+            }
+            self.column_scroll[column as usize] = offset;
+        }
+    }
+
     /// Move to next column
     pub fn select_next_column(&mut self) {
         self.selected_column = self.selected_column.next();
@@ -198,6 +824,239 @@ impl App {
         self.selected_task_index = 0;
     }
 
+    /// Open the detail view for the selected task
+    pub fn open_task_detail(&mut self, db: &Database) -> Result<(), OperationError> {
+        let Some(task_id) = self.selected_task().map(|t| t.id.clone()) else {
+            return Ok(());
+        };
+        self.comments = tasks::get_task_comments(db, &task_id)?;
+        self.view_mode = ViewMode::TaskDetail;
+        Ok(())
+    }
+
+    /// Open the burndown chart for the current feature
+    pub fn open_burndown(&mut self, db: &Database) -> Result<(), OperationError> {
+        let Some(feature) = &self.current_feature else {
+            self.set_status("No feature selected");
+            return Ok(());
+        };
+        self.burndown = metrics::get_feature_burndown(db, &feature.id)?;
+        self.view_mode = ViewMode::Burndown;
+        Ok(())
+    }
+
+    /// Open the dependency graph for the current feature
+    pub fn open_dependency_graph(&mut self, db: &Database) -> Result<(), OperationError> {
+        let Some(feature) = &self.current_feature else {
+            self.set_status("No feature selected");
+            return Ok(());
+        };
+        self.dependency_edges = tasks::get_feature_dependencies(db, &feature.id)?;
+        self.external_dependencies = tasks::get_external_dependencies(db, &feature.id)?;
+        self.graph_scroll = 0;
+        self.view_mode = ViewMode::DependencyGraph;
+        Ok(())
+    }
+
+    /// Open the comment form for the selected task
+    pub fn open_comment_form(&mut self) {
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+        let form = Form::new(
+            format!("Comment on {}", task.id),
+            vec![("Comment", String::new())],
+        );
+        self.open_form(form, ViewMode::AddComment);
+    }
+
+    /// Open the task creation form for the current feature
+    pub fn open_create_form(&mut self) {
+        let feature_id = self
+            .current_feature
+            .as_ref()
+            .map(|f| f.id.clone())
+            .unwrap_or_default();
+
+        let form = Form::new(
+            "New Task",
+            vec![
+                ("Title", String::new()),
+                ("Description", String::new()),
+                ("Priority", "100".to_string()),
+                ("Estimate (h)", String::new()),
+                ("Feature", feature_id),
+            ],
+        );
+        self.open_form(form, ViewMode::CreateTask);
+    }
+
+    /// Open the edit form for the selected task
+    pub fn open_edit_form(&mut self) {
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+
+        let form = Form::new(
+            format!("Edit {}", task.id),
+            vec![
+                ("Title", task.title.clone()),
+                (
+                    "Description (- clears)",
+                    task.description.clone().unwrap_or_default(),
+                ),
+                ("Priority", task.priority.to_string()),
+                (
+                    "Estimate (h, - clears)",
+                    task.estimated_hours.map(|h| h.to_string()).unwrap_or_default(),
+                ),
+            ],
+        );
+        self.open_form(form, ViewMode::EditTask);
+    }
+
+    /// Labels attached to a task
+    pub fn labels_of(&self, task_id: &str) -> &[String] {
+        self.task_labels
+            .get(task_id)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Task count and limit for a column with a WIP limit
+    ///
+    /// Counts every task in the column, ignoring the search filter, since
+    /// the limit applies to the whole column.
+    pub fn wip_of(&self, column: Column) -> Option<(usize, i64)> {
+        let limit = self.wip_limits[column as usize]?;
+        let status = column.to_status();
+        let count = self.tasks.iter().filter(|t| t.status == status).count();
+        Some((count, limit))
+    }
+
+    /// Checklist items of a task
+    pub fn checklist_of(&self, task_id: &str) -> &[ChecklistItem] {
+        self.checklists
+            .get(task_id)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Count active blockers on a task
+    pub fn blocker_count(&self, task_id: &str) -> usize {
+        self.blockers.iter().filter(|b| b.task_id == task_id).count()
+    }
+
+    /// Get the blocker highlighted in the blockers panel
+    pub fn selected_blocker(&self) -> Option<&Blocker> {
+        self.blockers.get(self.selected_blocker_index)
+    }
+
+    /// Highlight a blocker by ID, returning false if it is not active
+    pub fn select_blocker_by_id(&mut self, blocker_id: &str) -> bool {
+        match self.blockers.iter().position(|b| b.id == blocker_id) {
+            Some(index) => {
+                self.selected_blocker_index = index;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Move the blockers panel highlight up
+    pub fn blocker_up(&mut self) {
+        self.selected_blocker_index = self.selected_blocker_index.saturating_sub(1);
+    }
+
+    /// Move the blockers panel highlight down
+    pub fn blocker_down(&mut self) {
+        if self.selected_blocker_index < self.blockers.len().saturating_sub(1) {
+            self.selected_blocker_index += 1;
+        }
+    }
+
+    /// Open the resolution notes form for the highlighted blocker
+    pub fn open_resolve_form(&mut self) {
+        let Some(blocker) = self.selected_blocker() else {
+            return;
+        };
+        let form = Form::new(
+            format!("Resolve {}", blocker.id),
+            vec![("Notes", String::new())],
+        );
+        self.open_form(form, ViewMode::ResolveBlocker);
+    }
+
+    /// Open the form for adding a blocker to the selected task
+    pub fn open_blocker_form(&mut self) {
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+        let form = Form::new(
+            format!("Block {}", task.id),
+            vec![
+                ("Type", "technical".to_string()),
+                ("Description", String::new()),
+                ("Blocking task", String::new()),
+            ],
+        );
+        self.open_form(form, ViewMode::AddBlocker);
+    }
+
+    /// Open the agent picker for the selected task
+    pub fn open_agent_picker(&mut self, db: &Database) -> Result<(), OperationError> {
+        if self.selected_task().is_none() {
+            return Ok(());
+        }
+
+        self.agent_choices = metrics::get_available_agents(db, None)?;
+        self.agent_choice_index = 0;
+        if self.agent_choices.is_empty() {
+            self.set_status("No agents with available capacity");
+        } else {
+            self.view_mode = ViewMode::AssignAgent;
+        }
+        Ok(())
+    }
+
+    /// Get the agent highlighted in the picker
+    pub fn selected_agent_choice(&self) -> Option<&AgentWorkload> {
+        self.agent_choices.get(self.agent_choice_index)
+    }
+
+    /// Move the picker highlight up
+    pub fn agent_choice_up(&mut self) {
+        self.agent_choice_index = self.agent_choice_index.saturating_sub(1);
+    }
+
+    /// Move the picker highlight down
+    pub fn agent_choice_down(&mut self) {
+        if self.agent_choice_index < self.agent_choices.len().saturating_sub(1) {
+            self.agent_choice_index += 1;
+        }
+    }
+
+    /// Show a form, remembering the current view
+    pub fn open_form(&mut self, form: Form, mode: ViewMode) {
+        self.form = Some(form);
+        self.form_return_view = self.view_mode;
+        self.view_mode = mode;
+    }
+
+    /// Close any open form and return to the previous view
+    pub fn close_form(&mut self) {
+        self.form = None;
+        self.view_mode = self.form_return_view;
+    }
+
+    /// Check if 'q' quits the app
+    ///
+    /// Only the board quits; other views close with 'q' like Esc, and forms
+    /// and the search bar take it as text.
+    pub fn q_quits(&self) -> bool {
+        self.view_mode == ViewMode::Board
+    }
+
     /// Set status message
     pub fn set_status(&mut self, message: impl Into<String>) {
         self.status_message = Some(message.into());
@@ -208,3 +1067,379 @@ impl App {
         self.status_message = None;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_parse() {
+        let filter = TaskFilter::parse("Parser @cli_dev #Bug p<50");
+        assert_eq!(filter.terms, vec!["parser".to_string()]);
+        assert_eq!(filter.agent.as_deref(), Some("cli_dev"));
+        assert_eq!(filter.labels, vec!["bug".to_string()]);
+        assert_eq!(filter.max_priority, Some(50));
+        assert!(TaskFilter::parse("   ").is_empty());
+    }
+
+    #[test]
+    fn test_filter_matches() {
+        let mut task = Task::new(
+            "T-001".to_string(),
+            "parser".to_string(),
+            "Implement CSV parser".to_string(),
+        );
+        task.priority = 10;
+        task.assigned_agent = Some("parser_developer".to_string());
+
+        let labels = vec!["bug".to_string(), "p0".to_string()];
+
+        assert!(TaskFilter::parse("csv").matches(&task, &[]));
+        assert!(TaskFilter::parse("@parser p<20").matches(&task, &[]));
+        assert!(!TaskFilter::parse("p<10").matches(&task, &[]));
+        assert!(!TaskFilter::parse("@cli").matches(&task, &[]));
+        assert!(!TaskFilter::parse("ofx").matches(&task, &[]));
+        assert!(TaskFilter::parse("#bug #p0").matches(&task, &labels));
+        assert!(!TaskFilter::parse("#tech-debt").matches(&task, &labels));
+        assert!(!TaskFilter::parse("#bug").matches(&task, &[]));
+    }
+
+    #[test]
+    fn test_my_agent_filter() {
+        let db = Database::in_memory().unwrap();
+        let mut app = App::new(&db).unwrap();
+
+        app.toggle_agent_filter();
+        assert!(app.filter.agent.is_none());
+        assert!(app.status_message.is_some());
+
+        app.my_agent = Some("CLI_Developer".to_string());
+        app.toggle_agent_filter();
+        assert_eq!(app.filter.agent.as_deref(), Some("cli_developer"));
+        app.toggle_agent_filter();
+        assert!(app.filter.agent.is_none());
+    }
+
+    #[test]
+    fn test_q_quits_only_from_board() {
+        let db = Database::in_memory().unwrap();
+        let mut app = App::new(&db).unwrap();
+        assert!(app.q_quits());
+
+        // Overlays close on 'q' instead of quitting
+        let q = crossterm::event::KeyEvent::from(crossterm::event::KeyCode::Char('q'));
+        for mode in [ViewMode::Blockers, ViewMode::AssignAgent] {
+            app.view_mode = mode;
+            assert!(!app.q_quits());
+            super::super::events::handle_key_event(&mut app, q, &db).unwrap();
+            assert_eq!(app.view_mode, ViewMode::Board);
+        }
+
+        let form = Form::new("Comment", vec![("Text", String::new())]);
+        app.open_form(form, ViewMode::AddComment);
+        assert!(!app.q_quits());
+    }
+
+    #[test]
+    fn test_hit_test() {
+        let db = Database::in_memory().unwrap();
+        let mut app = App::new(&db).unwrap();
+        app.tasks = vec![Task::new(
+            "T-001".to_string(),
+            "parser".to_string(),
+            "Implement CSV parser".to_string(),
+        )];
+        app.column_areas = vec![
+            (Column::Todo, Rect::new(0, 3, 20, 30)),
+            (Column::InProgress, Rect::new(20, 3, 20, 30)),
+        ];
+
+        assert_eq!(app.hit_test(5, 4, 5), Some((Column::Todo, Some(0))));
+        assert_eq!(app.hit_test(5, 12, 5), Some((Column::Todo, None)));
+        assert_eq!(app.hit_test(25, 4, 5), Some((Column::InProgress, None)));
+        assert_eq!(app.hit_test(45, 4, 5), None);
+    }
+
+    #[test]
+    fn test_refresh_if_changed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("kanban.db");
+        let db = Database::open(&path).unwrap();
+        let mut app = App::new(&db).unwrap();
+        assert!(!app.refresh_if_changed(&db).unwrap());
+
+        // Another process adds a feature and task
+        let other = Database::open(&path).unwrap();
+        let feature = features::create_feature(
+            &other,
+            crate::models::CreateFeatureRequest {
+                name: "Parser".to_string(),
+                description: None,
+                color: None,
+            },
+        )
+        .unwrap();
+        tasks::create_task(
+            &other,
+            crate::models::TaskBuilder::new()
+                .feature_id(&feature.id)
+                .title("Parse CSV")
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert!(app.refresh_if_changed(&db).unwrap());
+        assert_eq!(app.tasks.len(), 1);
+        assert!(!app.refresh_if_changed(&db).unwrap());
+    }
+
+    #[test]
+    fn test_refresh_keeps_selected_blocker() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("kanban.db");
+        let db = Database::open(&path).unwrap();
+        let feature = features::create_feature(
+            &db,
+            crate::models::CreateFeatureRequest {
+                name: "Parser".to_string(),
+                description: None,
+                color: None,
+            },
+        )
+        .unwrap();
+        let mut blocker_ids = Vec::new();
+        for title in ["Parse CSV", "Parse JSON"] {
+            let task = tasks::create_task(
+                &db,
+                crate::models::TaskBuilder::new()
+                    .feature_id(&feature.id)
+                    .title(title)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+            let blocker = blockers::add_blocker(
+                &db,
+                crate::models::CreateBlockerRequest {
+                    task_id: task.id,
+                    blocker_type: crate::state_machine::BlockerType::Technical,
+                    description: format!("{} is stuck", title),
+                    blocking_task_id: None,
+                },
+            )
+            .unwrap();
+            blocker_ids.push(blocker.id);
+        }
+
+        let mut app = App::new(&db).unwrap();
+        app.refresh_if_changed(&db).unwrap();
+        assert!(app.select_blocker_by_id(&blocker_ids[1]));
+        assert_eq!(app.selected_blocker_index, 0);
+        app.open_resolve_form();
+
+        // Another process adds a blocker, which is listed first (newest)
+        let other = Database::open(&path).unwrap();
+        blockers::add_blocker(
+            &other,
+            crate::models::CreateBlockerRequest {
+                task_id: app.tasks[0].id.clone(),
+                blocker_type: crate::state_machine::BlockerType::External,
+                description: "Waiting on vendor".to_string(),
+                blocking_task_id: None,
+            },
+        )
+        .unwrap();
+        assert!(app.refresh_if_changed(&db).unwrap());
+        assert_eq!(app.selected_blocker().unwrap().id, blocker_ids[1]);
+        assert_eq!(app.view_mode, ViewMode::ResolveBlocker);
+
+        // Resolving the selected blocker itself closes the form
+        blockers::resolve_blocker(&other, &blocker_ids[1], None).unwrap();
+        assert!(app.refresh_if_changed(&db).unwrap());
+        assert!(app.form.is_none());
+        assert_ne!(app.view_mode, ViewMode::ResolveBlocker);
+    }
+
+    #[test]
+    fn test_swimlanes() {
+        let db = Database::in_memory().unwrap();
+        let mut app = App::new(&db).unwrap();
+        let mut tasks: Vec<Task> = (1..=4)
+            .map(|i| Task::new(format!("T-{:03}", i), "parser".to_string(), format!("Task {}", i)))
+            .collect();
+        tasks[0].assigned_agent = Some("parser_developer".to_string());
+        tasks[1].priority = 10;
+        tasks[2].assigned_agent = Some("cli_developer".to_string());
+        tasks[3].assigned_agent = Some("parser_developer".to_string());
+        app.tasks = tasks;
+        app.column_areas = vec![(Column::Todo, Rect::new(0, 0, 20, 3 * (CARD_HEIGHT + 1) + 2))];
+
+        app.swimlanes = Swimlanes::Agent;
+        assert_eq!(app.lanes(), vec!["cli_developer", "parser_developer", "Unassigned"]);
+        let ids: Vec<&str> = app
+            .tasks_for_column(&Column::Todo)
+            .iter()
+            .map(|t| t.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["T-003", "T-001", "T-004", "T-002"]);
+
+        let cells = app.lane_cells(Column::Todo);
+        assert_eq!(cells.len(), 3);
+        assert_eq!((cells[1].start, cells[1].len), (1, 2));
+        // Lane headers are not cards; the first card row of lane 2 is T-001
+        assert_eq!(app.hit_test(5, cells[1].header.y, CARD_HEIGHT), Some((Column::Todo, None)));
+        assert_eq!(app.hit_test(5, cells[1].cards.y, CARD_HEIGHT), Some((Column::Todo, Some(1))));
+
+        // Selecting the second card in a one-card-high lane scrolls that lane
+        app.selected_task_index = 2;
+        assert_eq!(app.lane_cells(Column::Todo)[1].offset, 1);
+
+        app.swimlanes = Swimlanes::Priority;
+        assert_eq!(app.lanes(), vec!["P0-24", "P100+"]);
+    }
+
+    #[test]
+    fn test_undo() {
+        let db = Database::in_memory().unwrap();
+        features::create_feature(
+            &db,
+            crate::models::CreateFeatureRequest {
+                name: "Parser".to_string(),
+                description: None,
+                color: None,
+            },
+        )
+        .unwrap();
+        let task = tasks::create_task(
+            &db,
+            crate::models::TaskBuilder::new()
+                .feature_id("parser")
+                .title("Parse CSV")
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+        let mut app = App::new(&db).unwrap();
+
+        tasks::update_task_status(&db, &task.id, TaskStatus::InProgress, "test", false).unwrap();
+        let task = tasks::update_task_status(&db, &task.id, TaskStatus::InQa, "test", false)
+            .unwrap();
+        app.push_undo(UndoAction::status(&task, TaskStatus::Done));
+        tasks::update_task_status(&db, &task.id, TaskStatus::Done, "test", false).unwrap();
+        app.push_undo(UndoAction::assignment(&task, "cli_developer"));
+        tasks::assign_task(&db, &task.id, "cli_developer", "test", false).unwrap();
+
+        app.undo(&db).unwrap();
+        assert_eq!(tasks::get_task(&db, &task.id).unwrap().assigned_agent, None);
+
+        // done is terminal, so the task is forced back to in-qa
+        app.undo(&db).unwrap();
+        assert_eq!(tasks::get_task(&db, &task.id).unwrap().status, TaskStatus::InQa);
+        assert_eq!(app.selected_task().map(|t| t.id.as_str()), Some(task.id.as_str()));
+
+        app.undo(&db).unwrap();
+        assert_eq!(app.status_message.as_deref(), Some("Nothing to undo"));
+    }
+
+    #[test]
+    fn test_undo_skips_outside_changes() {
+        let db = Database::in_memory().unwrap();
+        features::create_feature(
+            &db,
+            crate::models::CreateFeatureRequest {
+                name: "Parser".to_string(),
+                description: None,
+                color: None,
+            },
+        )
+        .unwrap();
+        let task = tasks::create_task(
+            &db,
+            crate::models::TaskBuilder::new()
+                .feature_id("parser")
+                .title("Parse CSV")
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+        let mut app = App::new(&db).unwrap();
+
+        // The TUI moves the task, then someone else finishes it
+        app.push_undo(UndoAction::status(&task, TaskStatus::InProgress));
+        tasks::update_task_status(&db, &task.id, TaskStatus::InProgress, "tui", false).unwrap();
+        tasks::update_task_status(&db, &task.id, TaskStatus::InQa, "other", false).unwrap();
+        tasks::update_task_status(&db, &task.id, TaskStatus::Done, "other", false).unwrap();
+
+        app.undo(&db).unwrap();
+        assert_eq!(tasks::get_task(&db, &task.id).unwrap().status, TaskStatus::Done);
+        assert!(app.status_message.as_deref().unwrap().starts_with("Cannot undo"));
+        assert!(app.undo_stack.is_empty());
+    }
+
+    #[test]
+    fn test_wip_of() {
+        let db = Database::in_memory().unwrap();
+        features::create_feature(
+            &db,
+            crate::models::CreateFeatureRequest {
+                name: "Parser".to_string(),
+                description: None,
+                color: None,
+            },
+        )
+        .unwrap();
+        wip::set_wip_limit(&db, TaskStatus::InProgress, None, 1).unwrap();
+        for (i, override_wip) in [false, true].into_iter().enumerate() {
+            let task = tasks::create_task(
+                &db,
+                crate::models::TaskBuilder::new()
+                    .feature_id("parser")
+                    .title(format!("Task {}", i))
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+            tasks::update_task_status(&db, &task.id, TaskStatus::InProgress, "test", override_wip)
+                .unwrap();
+        }
+
+        let mut app = App::new(&db).unwrap();
+        assert_eq!(app.wip_of(Column::InProgress), Some((2, 1)));
+        assert_eq!(app.wip_of(Column::Todo), None);
+
+        // The search filter does not change the count against the limit
+        app.filter = TaskFilter::parse("nothing-matches");
+        assert_eq!(app.wip_of(Column::InProgress), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_column_scroll() {
+        let db = Database::in_memory().unwrap();
+        let mut app = App::new(&db).unwrap();
+        app.tasks = (1..=10)
+            .map(|i| Task::new(format!("T-{:03}", i), "parser".to_string(), format!("Task {}", i)))
+            .collect();
+        // Room for 3 cards between the borders
+        app.column_areas = vec![(Column::Todo, Rect::new(0, 0, 20, 3 * CARD_HEIGHT + 2))];
+        assert_eq!(app.visible_cards(Column::Todo), 3);
+
+        app.page_down();
+        app.page_down();
+        app.update_scroll();
+        assert_eq!(app.selected_task_index, 6);
+        assert_eq!(app.column_scroll[Column::Todo as usize], 4);
+        assert_eq!(app.hit_test(5, 1, CARD_HEIGHT), Some((Column::Todo, Some(4))));
+
+        app.page_down();
+        app.page_down();
+        app.update_scroll();
+        assert_eq!(app.selected_task_index, 9);
+        assert_eq!(app.column_scroll[Column::Todo as usize], 7);
+
+        app.page_up();
+        app.update_scroll();
+        assert_eq!(app.selected_task_index, 6);
+        assert_eq!(app.column_scroll[Column::Todo as usize], 6);
+    }
+}
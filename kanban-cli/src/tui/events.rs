@@ -1,12 +1,15 @@
 //! Keyboard event handling for the TUI
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 
 use crate::db::Database;
-use crate::operations::{tasks, OperationError};
-use crate::state_machine::{StateMachine, TaskStatus};
+use crate::models::{CreateBlockerRequest, TaskBuilder, UpdateTaskRequest};
+use crate::operations::{blockers, features, tasks, OperationError};
+use crate::state_machine::{BlockerType, StateMachine, TaskStatus};
 
-use super::app::{App, ViewMode};
+use super::app::{App, Column, UndoAction, ViewMode};
+use super::widgets::form::{Form, FormAction};
+use super::widgets::task_card::CARD_HEIGHT;
 
 /// Handle a key event
 pub fn handle_key_event(
@@ -21,9 +24,99 @@ pub fn handle_key_event(
         ViewMode::Board => handle_board_keys(app, key, db),
         ViewMode::TaskDetail => handle_detail_keys(app, key),
         ViewMode::Help => handle_help_keys(app, key),
+        ViewMode::CreateTask
+        | ViewMode::EditTask
+        | ViewMode::ResolveBlocker
+        | ViewMode::AddBlocker
+        | ViewMode::AddComment => handle_form_keys(app, key, db),
+        ViewMode::AssignAgent => handle_agent_picker_keys(app, key, db),
+        ViewMode::Search => handle_search_keys(app, key),
+        ViewMode::Blockers => handle_blocker_keys(app, key, db),
+        ViewMode::Burndown => handle_chart_keys(app, key),
+        ViewMode::DependencyGraph => handle_graph_keys(app, key),
     }
 }
 
+/// Handle a mouse event (board view only)
+pub fn handle_mouse_event(
+    app: &mut App,
+    mouse: MouseEvent,
+    db: &Database,
+) -> Result<(), OperationError> {
+    if app.view_mode != ViewMode::Board {
+        return Ok(());
+    }
+
+    let hit = app.hit_test(mouse.column, mouse.row, CARD_HEIGHT);
+
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            app.clear_status();
+            if let Some((column, card)) = hit {
+                app.selected_column = column;
+                app.selected_task_index = card.unwrap_or(0);
+                app.drag_task_id = card.and_then(|_| app.selected_task()).map(|t| t.id.clone());
+            }
+        }
+        MouseEventKind::Drag(MouseButton::Left) => {
+            // Highlight the column under the cursor as the drop target
+            if let (Some(_), Some((column, _))) = (&app.drag_task_id, hit) {
+                app.selected_column = column;
+            }
+        }
+        MouseEventKind::Up(MouseButton::Left) => {
+            if let Some(task_id) = app.drag_task_id.take() {
+                match hit {
+                    Some((column, _)) => drop_task(app, db, &task_id, column)?,
+                    None => app.select_task_by_id(&task_id),
+                }
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Move a dragged task into the column it was dropped on
+fn drop_task(
+    app: &mut App,
+    db: &Database,
+    task_id: &str,
+    column: Column,
+) -> Result<(), OperationError> {
+    let Some(task) = app.tasks.iter().find(|t| t.id == task_id) else {
+        return Ok(());
+    };
+    let from = task.status;
+    let to = column.to_status();
+    let undo = UndoAction::status(task, to);
+
+    if from == to {
+        app.select_task_by_id(task_id);
+        return Ok(());
+    }
+
+    if !StateMachine::can_transition(&from, &to) {
+        app.select_task_by_id(task_id);
+        app.set_status(format!("Cannot move {} from {} to {}", task_id, from, to));
+        return Ok(());
+    }
+
+    match tasks::update_task_status(db, task_id, to, "tui", false) {
+        Ok(_) => {
+            app.push_undo(undo);
+            app.refresh_all(db)?;
+            app.set_status(format!("Moved {} to {}", task_id, to));
+        }
+        Err(e) => {
+            app.set_status(format!("Error: {}", e));
+        }
+    }
+    app.select_task_by_id(task_id);
+    Ok(())
+}
+
 /// Handle keys in board view
 fn handle_board_keys(
     app: &mut App,
@@ -44,12 +137,16 @@ fn handle_board_keys(
         KeyCode::Char('l') | KeyCode::Right => {
             app.select_next_column();
         }
+        KeyCode::PageDown => {
+            app.page_down();
+        }
+        KeyCode::PageUp => {
+            app.page_up();
+        }
 
         // View task details
         KeyCode::Enter => {
-            if app.selected_task().is_some() {
-                app.view_mode = ViewMode::TaskDetail;
-            }
+            app.open_task_detail(db)?;
         }
 
         // Move task forward (to next valid state)
@@ -57,8 +154,10 @@ fn handle_board_keys(
             if let Some(task) = app.selected_task() {
                 let valid = StateMachine::valid_transitions(&task.status);
                 if let Some(next_status) = valid.first() {
-                    match tasks::update_task_status(db, &task.id, *next_status, "tui") {
+                    let undo = UndoAction::status(task, *next_status);
+                    match tasks::update_task_status(db, &task.id, *next_status, "tui", false) {
                         Ok(_) => {
+                            app.push_undo(undo);
                             app.refresh_tasks(db)?;
                             app.update_metrics(db)?;
                             app.set_status(format!("Moved to {}", next_status));
@@ -77,8 +176,16 @@ fn handle_board_keys(
         KeyCode::Char('p') => {
             if let Some(task) = app.selected_task() {
                 if StateMachine::can_transition(&task.status, &TaskStatus::InProgress) {
-                    match tasks::update_task_status(db, &task.id, TaskStatus::InProgress, "tui") {
+                    let undo = UndoAction::status(task, TaskStatus::InProgress);
+                    match tasks::update_task_status(
+                        db,
+                        &task.id,
+                        TaskStatus::InProgress,
+                        "tui",
+                        false,
+                    ) {
                         Ok(_) => {
+                            app.push_undo(undo);
                             app.refresh_tasks(db)?;
                             app.update_metrics(db)?;
                             app.set_status("Moved to in-progress");
@@ -97,8 +204,10 @@ fn handle_board_keys(
         KeyCode::Char('d') => {
             if let Some(task) = app.selected_task() {
                 if StateMachine::can_transition(&task.status, &TaskStatus::Done) {
-                    match tasks::update_task_status(db, &task.id, TaskStatus::Done, "tui") {
+                    let undo = UndoAction::status(task, TaskStatus::Done);
+                    match tasks::update_task_status(db, &task.id, TaskStatus::Done, "tui", false) {
                         Ok(_) => {
+                            app.push_undo(undo);
                             app.refresh_tasks(db)?;
                             app.update_metrics(db)?;
                             app.set_status("Marked as done");
@@ -113,16 +222,66 @@ fn handle_board_keys(
             }
         }
 
+        // Create a new task
+        KeyCode::Char('n') => {
+            app.open_create_form();
+        }
+
+        // Search and filter cards
+        KeyCode::Char('/') => {
+            app.view_mode = ViewMode::Search;
+        }
+        KeyCode::Char('A') => {
+            app.toggle_agent_filter();
+        }
+        KeyCode::Char('s') => {
+            app.cycle_swimlanes();
+        }
+        KeyCode::Esc if !app.filter.is_empty() => {
+            app.clear_filter();
+            app.set_status("Filter cleared");
+        }
+
+        // Assign the selected task to an agent
+        KeyCode::Char('a') => {
+            app.open_agent_picker(db)?;
+        }
+
+        // Show burndown chart
+        KeyCode::Char('g') => {
+            app.open_burndown(db)?;
+        }
+
+        // Show dependency graph
+        KeyCode::Char('D') => {
+            app.open_dependency_graph(db)?;
+        }
+
+        // Add a blocker to the selected task
+        KeyCode::Char('B') => {
+            app.open_blocker_form();
+        }
+
+        // Show blockers panel
+        KeyCode::Char('b') => {
+            app.view_mode = ViewMode::Blockers;
+        }
+
         // Show help
         KeyCode::Char('?') => {
             app.view_mode = ViewMode::Help;
         }
 
+        // Undo the last move or assignment
+        KeyCode::Char('u') => {
+            if let Err(e) = app.undo(db) {
+                app.set_status(format!("Undo failed: {}", e));
+            }
+        }
+
         // Refresh
         KeyCode::Char('r') => {
-            app.refresh_tasks(db)?;
-            app.refresh_blockers(db)?;
-            app.update_metrics(db)?;
+            app.refresh_all(db)?;
             app.set_status("Refreshed");
         }
 
@@ -138,6 +297,37 @@ fn handle_detail_keys(app: &mut App, key: KeyEvent) -> Result<(), OperationError
         KeyCode::Esc | KeyCode::Char('q') | KeyCode::Enter => {
             app.view_mode = ViewMode::Board;
         }
+        KeyCode::Char('e') => {
+            app.open_edit_form();
+        }
+        KeyCode::Char('c') => {
+            app.open_comment_form();
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle keys in the burndown chart view
+fn handle_chart_keys(app: &mut App, key: KeyEvent) -> Result<(), OperationError> {
+    if let KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('g') = key.code {
+        app.view_mode = ViewMode::Board;
+    }
+    Ok(())
+}
+
+/// Handle keys in the dependency graph view
+fn handle_graph_keys(app: &mut App, key: KeyEvent) -> Result<(), OperationError> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('D') => {
+            app.view_mode = ViewMode::Board;
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.graph_scroll = app.graph_scroll.saturating_add(1);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.graph_scroll = app.graph_scroll.saturating_sub(1);
+        }
         _ => {}
     }
     Ok(())
@@ -153,3 +343,277 @@ fn handle_help_keys(app: &mut App, key: KeyEvent) -> Result<(), OperationError>
     }
     Ok(())
 }
+
+/// Handle keys while typing a search query
+fn handle_search_keys(app: &mut App, key: KeyEvent) -> Result<(), OperationError> {
+    match key.code {
+        KeyCode::Esc => {
+            app.clear_filter();
+            app.view_mode = ViewMode::Board;
+        }
+        KeyCode::Enter => {
+            app.view_mode = ViewMode::Board;
+        }
+        KeyCode::Backspace => {
+            let mut query = app.search_query.clone();
+            query.pop();
+            app.set_search_query(query);
+        }
+        KeyCode::Char(c) => {
+            let query = format!("{}{}", app.search_query, c);
+            app.set_search_query(query);
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle keys in the blockers panel
+fn handle_blocker_keys(app: &mut App, key: KeyEvent, db: &Database) -> Result<(), OperationError> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('b') => {
+            app.view_mode = ViewMode::Board;
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.blocker_down();
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.blocker_up();
+        }
+        KeyCode::Char('r') | KeyCode::Enter => {
+            app.open_resolve_form();
+        }
+        KeyCode::Char('e') => {
+            if let Some(blocker_id) = app.selected_blocker().map(|b| b.id.clone()) {
+                match blockers::escalate_blocker(db, &blocker_id) {
+                    Ok(_) => {
+                        app.refresh_all(db)?;
+                        app.set_status(format!("Escalated blocker: {}", blocker_id));
+                    }
+                    Err(e) => {
+                        app.set_status(format!("Error: {}", e));
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle keys in the agent assignment picker
+fn handle_agent_picker_keys(
+    app: &mut App,
+    key: KeyEvent,
+    db: &Database,
+) -> Result<(), OperationError> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.view_mode = ViewMode::Board;
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.agent_choice_down();
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.agent_choice_up();
+        }
+        KeyCode::Enter => {
+            let task_id = app.selected_task().map(|t| t.id.clone());
+            let agent_id = app.selected_agent_choice().map(|w| w.agent.id.clone());
+            let undo = app
+                .selected_task()
+                .zip(agent_id.as_deref())
+                .map(|(task, agent_id)| UndoAction::assignment(task, agent_id));
+            app.view_mode = ViewMode::Board;
+
+            if let (Some(task_id), Some(agent_id), Some(undo)) = (task_id, agent_id, undo) {
+                match tasks::assign_task(db, &task_id, &agent_id, "tui", false) {
+                    Ok(_) => {
+                        app.push_undo(undo);
+                        app.refresh_tasks(db)?;
+                        app.select_task_by_id(&task_id);
+                        app.set_status(format!("Assigned {} to {}", task_id, agent_id));
+                    }
+                    Err(e) => {
+                        app.set_status(format!("Error: {}", e));
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle keys while a form is open
+fn handle_form_keys(app: &mut App, key: KeyEvent, db: &Database) -> Result<(), OperationError> {
+    let action = match app.form.as_mut() {
+        Some(form) => form.handle_key(key),
+        None => FormAction::Cancel,
+    };
+
+    match action {
+        FormAction::None => {}
+        FormAction::Cancel => app.close_form(),
+        FormAction::Submit => {
+            let Some(form) = app.form.clone() else {
+                return Ok(());
+            };
+            let result = match app.view_mode {
+                ViewMode::CreateTask => submit_create_task(app, db, &form),
+                ViewMode::EditTask => submit_edit_task(app, db, &form),
+                ViewMode::ResolveBlocker => submit_resolve_blocker(app, db, &form),
+                ViewMode::AddBlocker => submit_add_blocker(app, db, &form),
+                ViewMode::AddComment => submit_comment(app, db, &form),
+                _ => Ok(()),
+            };
+            match result {
+                Ok(()) => app.close_form(),
+                Err(e) => app.set_status(format!("Error: {}", e)),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Create a task from the creation form
+fn submit_create_task(app: &mut App, db: &Database, form: &Form) -> Result<(), OperationError> {
+    if form.value("Title").is_empty() {
+        return Err(OperationError::Validation("Title is required".to_string()));
+    }
+    features::get_feature(db, form.value("Feature"))?;
+
+    let mut builder = TaskBuilder::new()
+        .feature_id(form.value("Feature"))
+        .title(form.value("Title"))
+        .priority(parse_field(form, "Priority")?.unwrap_or(100));
+    if let Some(desc) = form.optional("Description") {
+        builder = builder.description(desc);
+    }
+    if let Some(est) = parse_field(form, "Estimate (h)")? {
+        builder = builder.estimated_hours(est);
+    }
+
+    let request = builder
+        .build()
+        .map_err(|e| OperationError::Validation(e.to_string()))?;
+    let task = tasks::create_task(db, request)?;
+
+    app.refresh_tasks(db)?;
+    app.update_metrics(db)?;
+    app.set_status(format!("Created task: {}", task.id));
+    Ok(())
+}
+
+/// Apply the edit form to the selected task
+fn submit_edit_task(app: &mut App, db: &Database, form: &Form) -> Result<(), OperationError> {
+    let Some(task_id) = app.selected_task().map(|t| t.id.clone()) else {
+        return Ok(());
+    };
+    // Blank fields stay unchanged; "-" clears the optional ones
+    let estimate = match form.value("Estimate (h, - clears)") {
+        "-" => Some(None),
+        _ => parse_field(form, "Estimate (h, - clears)")?.map(Some),
+    };
+    let request = UpdateTaskRequest {
+        title: form.optional("Title"),
+        description: form
+            .optional("Description (- clears)")
+            .map(|d| (d != "-").then_some(d)),
+        priority: parse_field(form, "Priority")?,
+        estimated_hours: estimate,
+        ..Default::default()
+    };
+
+    tasks::update_task(db, &task_id, &request, "tui")?;
+
+    app.refresh_tasks(db)?;
+    app.update_metrics(db)?;
+    app.select_task_by_id(&task_id);
+    app.set_status(format!("Updated task: {}", task_id));
+    Ok(())
+}
+
+/// Resolve the highlighted blocker with the form's notes
+fn submit_resolve_blocker(app: &mut App, db: &Database, form: &Form) -> Result<(), OperationError> {
+    let Some(blocker_id) = app.selected_blocker().map(|b| b.id.clone()) else {
+        return Ok(());
+    };
+    let notes = form.optional("Notes");
+
+    blockers::resolve_blocker(db, &blocker_id, notes.as_deref())?;
+
+    app.refresh_all(db)?;
+    app.set_status(format!("Resolved blocker: {}", blocker_id));
+    Ok(())
+}
+
+/// Add a blocker to the selected task from the blocker form
+fn submit_add_blocker(app: &mut App, db: &Database, form: &Form) -> Result<(), OperationError> {
+    let Some((task_id, previous)) = app.selected_task().map(|t| (t.id.clone(), t.status)) else {
+        return Ok(());
+    };
+    let blocker_type: BlockerType = form.value("Type").parse().map_err(|_| {
+        OperationError::Validation(format!("Invalid blocker type: {}", form.value("Type")))
+    })?;
+    if form.value("Description").is_empty() {
+        return Err(OperationError::Validation("Description is required".to_string()));
+    }
+    let blocking_task_id = form.optional("Blocking task");
+    if let Some(blocking) = &blocking_task_id {
+        tasks::get_task(db, blocking)?;
+    }
+
+    let blocker = blockers::add_blocker(
+        db,
+        CreateBlockerRequest {
+            task_id: task_id.clone(),
+            blocker_type,
+            description: form.value("Description").to_string(),
+            blocking_task_id,
+        },
+    )?;
+
+    // add_blocker moves in-progress tasks to blocked; other states cannot be blocked
+    app.refresh_all(db)?;
+    app.select_task_by_id(&task_id);
+    let message = match app.selected_task().map(|t| t.status) {
+        _ if previous == TaskStatus::Blocked => {
+            format!("Added {}; {} was already blocked", blocker.id, task_id)
+        }
+        Some(TaskStatus::Blocked) => format!("Added {}; {} moved to blocked", blocker.id, task_id),
+        Some(status) => format!(
+            "Added {}; {} was NOT moved and stays in {} (only in-progress tasks can be blocked)",
+            blocker.id, task_id, status
+        ),
+        None => format!("Added {}", blocker.id),
+    };
+    app.set_status(message);
+    Ok(())
+}
+
+/// Add a comment to the selected task
+fn submit_comment(app: &mut App, db: &Database, form: &Form) -> Result<(), OperationError> {
+    let Some(task_id) = app.selected_task().map(|t| t.id.clone()) else {
+        return Ok(());
+    };
+
+    let comment = tasks::add_task_comment(db, &task_id, "tui", form.value("Comment"))?;
+    app.comments.push(comment);
+    app.set_status(format!("Commented on {}", task_id));
+    Ok(())
+}
+
+/// Parse an optional numeric form field
+fn parse_field<T: std::str::FromStr>(
+    form: &Form,
+    label: &str,
+) -> Result<Option<T>, OperationError> {
+    form.optional(label)
+        .map(|v| {
+            v.parse()
+                .map_err(|_| OperationError::Validation(format!("Invalid {}: {}", label, v)))
+        })
+        .transpose()
+}
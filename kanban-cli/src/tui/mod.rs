@@ -2,11 +2,13 @@
 
 mod app;
 mod events;
+pub mod theme;
 mod ui;
 pub mod widgets;
 
 pub use app::App;
 
+use crate::config::Config;
 use crate::db::Database;
 use crate::operations::OperationError;
 
@@ -19,7 +21,7 @@ use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
 
 /// Run the TUI application
-pub fn run(db: &Database) -> Result<(), OperationError> {
+pub fn run(db: &Database, config: &Config) -> Result<(), OperationError> {
     // Setup terminal
     enable_raw_mode().map_err(|e| OperationError::Validation(e.to_string()))?;
     let mut stdout = io::stdout();
@@ -31,6 +33,12 @@ pub fn run(db: &Database) -> Result<(), OperationError> {
 
     // Create app state
     let mut app = App::new(db)?;
+    let (theme, problems) = theme::Theme::from_config(&config.theme);
+    app.theme = theme;
+    app.my_agent = config.agent.clone();
+    if !problems.is_empty() {
+        app.set_status(format!("Theme config ignored: {}", problems.join("; ")));
+    }
 
     // Main loop
     loop {
@@ -41,20 +49,28 @@ pub fn run(db: &Database) -> Result<(), OperationError> {
         if event::poll(std::time::Duration::from_millis(100))
             .map_err(|e| OperationError::Validation(e.to_string()))?
         {
-            if let Event::Key(key) = event::read()
-                .map_err(|e| OperationError::Validation(e.to_string()))?
-            {
-                // Handle quit
-                if key.code == KeyCode::Char('q')
-                    || (key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL))
-                {
-                    break;
-                }
+            match event::read().map_err(|e| OperationError::Validation(e.to_string()))? {
+                Event::Key(key) => {
+                    // Handle quit ('q' closes other views and is text in forms)
+                    if (key.code == KeyCode::Char('q') && app.q_quits())
+                        || (key.code == KeyCode::Char('c')
+                            && key.modifiers.contains(KeyModifiers::CONTROL))
+                    {
+                        break;
+                    }
 
-                // Handle other keys
-                events::handle_key_event(&mut app, key, db)?;
+                    // Handle other keys
+                    events::handle_key_event(&mut app, key, db)?;
+                }
+                Event::Mouse(mouse) => events::handle_mouse_event(&mut app, mouse, db)?,
+                _ => {}
             }
         }
+
+        // Pick up changes made by agents through the CLI (retried next tick on failure)
+        if let Err(e) = app.refresh_if_changed(db) {
+            app.set_status(format!("Auto-refresh failed: {}", e));
+        }
     }
 
     // Restore terminal
@@ -0,0 +1,297 @@
+//! Color themes for the TUI
+
+use ratatui::style::{Color, Modifier, Style};
+
+use crate::config::{StatusColors, ThemeConfig};
+use crate::state_machine::TaskStatus;
+
+/// One color per task status
+#[derive(Debug, Clone, Copy)]
+pub struct StatusPalette {
+    pub todo: Color,
+    pub in_progress: Color,
+    pub blocked: Color,
+    pub in_qa: Color,
+    pub done: Color,
+}
+
+impl StatusPalette {
+    /// Get the color for a status
+    pub fn get(&self, status: TaskStatus) -> Color {
+        match status {
+            TaskStatus::Todo => self.todo,
+            TaskStatus::InProgress => self.in_progress,
+            TaskStatus::Blocked => self.blocked,
+            TaskStatus::InQa => self.in_qa,
+            TaskStatus::Done => self.done,
+        }
+    }
+
+    fn uniform(color: Color) -> Self {
+        Self {
+            todo: color,
+            in_progress: color,
+            blocked: color,
+            in_qa: color,
+            done: color,
+        }
+    }
+
+    fn apply(&mut self, overrides: &StatusColors, section: &str, problems: &mut Vec<String>) {
+        let fields = [
+            (&mut self.todo, &overrides.todo, "todo"),
+            (&mut self.in_progress, &overrides.in_progress, "in_progress"),
+            (&mut self.blocked, &overrides.blocked, "blocked"),
+            (&mut self.in_qa, &overrides.in_qa, "in_qa"),
+            (&mut self.done, &overrides.done, "done"),
+        ];
+        for (target, value, key) in fields {
+            override_color(target, value, &format!("{}.{}", section, key), problems);
+        }
+    }
+}
+
+/// Resolved colors used when drawing the TUI
+#[derive(Debug, Clone)]
+pub struct Theme {
+    /// Column title colors
+    pub columns: StatusPalette,
+    /// Colors for status text and agent names on cards
+    pub statuses: StatusPalette,
+    /// Headers, IDs, and popup borders
+    pub accent: Color,
+    /// Unfocused column borders
+    pub border: Color,
+    /// Focused column border
+    pub selected_border: Color,
+    /// Secondary text (hints, card info lines)
+    pub muted: Color,
+    /// Style for the highlighted card or list row
+    pub selected: Style,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// Built-in dark theme (the original palette)
+    pub fn dark() -> Self {
+        let statuses = StatusPalette {
+            todo: Color::White,
+            in_progress: Color::Yellow,
+            blocked: Color::Red,
+            in_qa: Color::Cyan,
+            done: Color::Green,
+        };
+        Self {
+            columns: statuses,
+            statuses,
+            accent: Color::Cyan,
+            border: Color::DarkGray,
+            selected_border: Color::Yellow,
+            muted: Color::DarkGray,
+            selected: Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// Built-in theme for light terminal backgrounds
+    pub fn light() -> Self {
+        let statuses = StatusPalette {
+            todo: Color::Black,
+            in_progress: Color::Rgb(176, 112, 0),
+            blocked: Color::Red,
+            in_qa: Color::Blue,
+            done: Color::Green,
+        };
+        Self {
+            columns: StatusPalette::uniform(Color::Blue),
+            statuses,
+            accent: Color::Blue,
+            border: Color::Gray,
+            selected_border: Color::Blue,
+            muted: Color::Gray,
+            selected: Style::default()
+                .bg(Color::Rgb(220, 220, 220))
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// Built-in high-contrast theme
+    pub fn high_contrast() -> Self {
+        let statuses = StatusPalette {
+            todo: Color::White,
+            in_progress: Color::LightYellow,
+            blocked: Color::LightRed,
+            in_qa: Color::LightCyan,
+            done: Color::LightGreen,
+        };
+        Self {
+            columns: StatusPalette::uniform(Color::White),
+            statuses,
+            accent: Color::LightCyan,
+            border: Color::White,
+            selected_border: Color::LightYellow,
+            muted: Color::White,
+            selected: Style::default()
+                .fg(Color::Black)
+                .bg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// Theme without any colors, relying on text modifiers
+    pub fn no_color() -> Self {
+        Self {
+            columns: StatusPalette::uniform(Color::Reset),
+            statuses: StatusPalette::uniform(Color::Reset),
+            accent: Color::Reset,
+            border: Color::Reset,
+            selected_border: Color::Reset,
+            muted: Color::Reset,
+            selected: Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD),
+        }
+    }
+
+    /// Look up a built-in theme by name
+    pub fn named(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" | "high_contrast" => Some(Self::high_contrast()),
+            "none" | "no-color" => Some(Self::no_color()),
+            _ => None,
+        }
+    }
+
+    /// Build the theme from config, honoring the NO_COLOR convention
+    ///
+    /// Also returns a description of each config value that was ignored
+    /// because it is not a known theme or color.
+    pub fn from_config(config: &ThemeConfig) -> (Self, Vec<String>) {
+        let (theme, problems) = Self::resolve(config);
+        if no_color_requested() {
+            (Self::no_color(), problems)
+        } else {
+            (theme, problems)
+        }
+    }
+
+    /// Apply config overrides on top of the configured base theme
+    fn resolve(config: &ThemeConfig) -> (Self, Vec<String>) {
+        let mut problems = Vec::new();
+        let mut theme = match config.base.as_deref() {
+            Some(name) => Self::named(name).unwrap_or_else(|| {
+                problems.push(format!("unknown base theme '{}'", name));
+                Self::default()
+            }),
+            None => Self::default(),
+        };
+
+        theme.columns.apply(&config.columns, "columns", &mut problems);
+        theme.statuses.apply(&config.statuses, "statuses", &mut problems);
+        override_color(&mut theme.accent, &config.accent, "accent", &mut problems);
+        override_color(&mut theme.border, &config.border, "border", &mut problems);
+        override_color(
+            &mut theme.selected_border,
+            &config.selected_border,
+            "selected_border",
+            &mut problems,
+        );
+        override_color(&mut theme.muted, &config.muted, "muted", &mut problems);
+        if let Some(fg) = parse_color(&config.selected_fg, "selected_fg", &mut problems) {
+            theme.selected = theme.selected.fg(fg);
+        }
+        if let Some(bg) = parse_color(&config.selected_bg, "selected_bg", &mut problems) {
+            theme.selected = theme.selected.bg(bg);
+        }
+
+        (theme, problems)
+    }
+}
+
+/// Check the NO_COLOR environment variable (https://no-color.org)
+fn no_color_requested() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
+/// Parse a configured color, noting values that are not colors
+fn parse_color(value: &Option<String>, key: &str, problems: &mut Vec<String>) -> Option<Color> {
+    let value = value.as_deref()?;
+    let color = value.parse().ok();
+    if color.is_none() {
+        problems.push(format!("unknown color '{}' for {}", value, key));
+    }
+    color
+}
+
+fn override_color(
+    target: &mut Color,
+    value: &Option<String>,
+    key: &str,
+    problems: &mut Vec<String>,
+) {
+    if let Some(color) = parse_color(value, key, problems) {
+        *target = color;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_themes() {
+        assert!(Theme::named("light").is_some());
+        assert!(Theme::named("High-Contrast").is_some());
+        assert!(Theme::named("solarized").is_none());
+    }
+
+    #[test]
+    fn test_config_overrides() {
+        let config = ThemeConfig {
+            base: Some("light".to_string()),
+            accent: Some("#ff8800".to_string()),
+            statuses: StatusColors {
+                blocked: Some("magenta".to_string()),
+                ..Default::default()
+            },
+            muted: Some("not-a-color".to_string()),
+            ..Default::default()
+        };
+
+        let (theme, problems) = Theme::resolve(&config);
+        assert_eq!(theme.accent, Color::Rgb(255, 136, 0));
+        assert_eq!(theme.statuses.get(TaskStatus::Blocked), Color::Magenta);
+        assert_eq!(theme.statuses.get(TaskStatus::InQa), Color::Blue);
+        // Unparseable colors keep the base theme value and are reported
+        assert_eq!(theme.muted, Theme::light().muted);
+        assert_eq!(problems, ["unknown color 'not-a-color' for muted"]);
+    }
+
+    #[test]
+    fn test_config_problems() {
+        let config = ThemeConfig {
+            base: Some("solarized".to_string()),
+            statuses: StatusColors {
+                done: Some("blu".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let (theme, problems) = Theme::resolve(&config);
+        assert_eq!(theme.accent, Theme::dark().accent);
+        assert_eq!(
+            problems,
+            [
+                "unknown base theme 'solarized'",
+                "unknown color 'blu' for statuses.done"
+            ]
+        );
+    }
+}
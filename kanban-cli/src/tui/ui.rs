@@ -1,36 +1,165 @@
 //! UI rendering for the TUI
 
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
+    symbols,
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    widgets::{
+        Axis, Block, Borders, Chart, Clear, Dataset, GraphType, List, ListItem, Paragraph,
+        Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap,
+    },
     Frame,
 };
 
-use super::app::{App, Column, ViewMode};
+use crate::models::Task;
+use crate::operations::checklists;
+
+use super::app::{App, Column, LaneCell, Swimlanes, ViewMode};
 use super::widgets;
 
 /// Main draw function
 pub fn draw(f: &mut Frame, app: &mut App) {
+    app.column_areas = column_areas(board_layout(f.area())[1]);
+    app.update_scroll();
+
     match app.view_mode {
-        ViewMode::Board => draw_board(f, app),
+        ViewMode::Board | ViewMode::Search => draw_board(f, app),
         ViewMode::TaskDetail => draw_task_detail(f, app),
         ViewMode::Help => draw_help(f, app),
+        ViewMode::Burndown => draw_burndown(f, app),
+        ViewMode::DependencyGraph => draw_dependency_graph(f, app),
+        ViewMode::CreateTask | ViewMode::AddBlocker => {
+            draw_board(f, app);
+            draw_form(f, app);
+        }
+        ViewMode::EditTask | ViewMode::AddComment => {
+            draw_task_detail(f, app);
+            draw_form(f, app);
+        }
+        ViewMode::AssignAgent => {
+            draw_board(f, app);
+            draw_agent_picker(f, app);
+        }
+        ViewMode::Blockers => {
+            draw_board(f, app);
+            draw_blockers(f, app);
+        }
+        ViewMode::ResolveBlocker => {
+            draw_board(f, app);
+            draw_blockers(f, app);
+            draw_form(f, app);
+        }
+    }
+}
+
+/// Draw the blockers panel for the current feature
+fn draw_blockers(f: &mut Frame, app: &App) {
+    let area = centered_rect(70, 60, f.area());
+
+    let items: Vec<ListItem> = if app.blockers.is_empty() {
+        vec![ListItem::new("No active blockers")]
+    } else {
+        app.blockers
+            .iter()
+            .enumerate()
+            .map(|(i, blocker)| {
+                let style = if i == app.selected_blocker_index {
+                    app.theme.selected
+                } else {
+                    Style::default()
+                };
+                ListItem::new(vec![
+                    Line::from(vec![
+                        Span::styled(
+                            format!("{:<8}", blocker.id),
+                            Style::default().fg(app.theme.statuses.blocked),
+                        ),
+                        Span::styled(
+                            format!("{:<20}", blocker.task_id),
+                            Style::default().fg(app.theme.accent),
+                        ),
+                        Span::raw(format!(
+                            "{:<14} {:.1}h",
+                            blocker.blocker_type,
+                            blocker.duration_hours()
+                        )),
+                    ]),
+                    Line::from(format!("  {}", blocker.description)),
+                ])
+                .style(style)
+            })
+            .collect()
+    };
+
+    let list = List::new(items).block(
+        Block::default()
+            .title(" Blockers [r] Resolve [e] Escalate [q/Esc] Close ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(app.theme.statuses.blocked)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(list, area);
+}
+
+/// Draw the agent assignment picker
+fn draw_agent_picker(f: &mut Frame, app: &App) {
+    let area = centered_rect(50, 60, f.area());
+
+    let items: Vec<ListItem> = app
+        .agent_choices
+        .iter()
+        .enumerate()
+        .map(|(i, workload)| {
+            let style = if i == app.agent_choice_index {
+                app.theme.selected
+            } else {
+                Style::default()
+            };
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{:<32}", workload.agent.name)),
+                Span::styled(
+                    format!(
+                        "{}/{} ({} free)",
+                        workload.current_tasks,
+                        workload.agent.max_concurrent_tasks,
+                        workload.remaining_capacity()
+                    ),
+                    Style::default().fg(app.theme.statuses.done),
+                ),
+            ]))
+            .style(style)
+        })
+        .collect();
+
+    let title = app
+        .selected_task()
+        .map(|t| format!(" Assign {} [Enter] Assign [q/Esc] Cancel ", t.id))
+        .unwrap_or_else(|| " Assign ".to_string());
+
+    let list = List::new(items).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(app.theme.accent)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(list, area);
+}
+
+/// Draw the active form as a popup
+fn draw_form(f: &mut Frame, app: &App) {
+    if let Some(form) = &app.form {
+        let area = centered_rect(60, 40, f.area());
+        widgets::form::render_form(f, form, area, &app.theme);
     }
 }
 
 /// Draw the main kanban board view
 fn draw_board(f: &mut Frame, app: &App) {
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3), // Header
-            Constraint::Min(10),   // Board
-            Constraint::Length(3), // Metrics
-            Constraint::Length(1), // Status/help
-        ])
-        .split(f.area());
+    let chunks = board_layout(f.area());
 
     // Header
     draw_header(f, app, chunks[0]);
@@ -45,6 +174,19 @@ fn draw_board(f: &mut Frame, app: &App) {
     draw_status_bar(f, app, chunks[3]);
 }
 
+/// Split the screen into header, board, metrics, and status rows
+fn board_layout(area: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Min(10),   // Board
+            Constraint::Length(3), // Metrics
+            Constraint::Length(1), // Status/help
+        ])
+        .split(area)
+}
+
 /// Draw the header
 fn draw_header(f: &mut Frame, app: &App, area: Rect) {
     let feature_name = app
@@ -53,8 +195,22 @@ fn draw_header(f: &mut Frame, app: &App, area: Rect) {
         .map(|f| format!("{} - {}", f.id, f.name))
         .unwrap_or_else(|| "No Feature".to_string());
 
-    let header = Paragraph::new(format!("KANBAN: {}", feature_name))
-        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+    let filter = if app.filter.is_empty() {
+        String::new()
+    } else if app.search_query.is_empty() {
+        "  [filtered]".to_string()
+    } else {
+        format!("  [filter: {}]", app.search_query)
+    };
+
+    let lanes = if app.swimlanes == Swimlanes::Off {
+        String::new()
+    } else {
+        format!("  [lanes: {}]", app.swimlanes.label())
+    };
+
+    let header = Paragraph::new(format!("KANBAN: {}{}{}", feature_name, filter, lanes))
+        .style(Style::default().fg(app.theme.accent).add_modifier(Modifier::BOLD))
         .block(
             Block::default()
                 .borders(Borders::ALL)
@@ -64,8 +220,8 @@ fn draw_header(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(header, area);
 }
 
-/// Draw the kanban columns
-fn draw_columns(f: &mut Frame, app: &App, area: Rect) {
+/// Split the board area into one rect per column
+pub fn column_areas(area: Rect) -> Vec<(Column, Rect)> {
     let column_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
@@ -77,8 +233,17 @@ fn draw_columns(f: &mut Frame, app: &App, area: Rect) {
         ])
         .split(area);
 
-    for (i, column) in Column::all().iter().enumerate() {
-        draw_column(f, app, *column, column_chunks[i]);
+    Column::all()
+        .iter()
+        .zip(column_chunks.iter())
+        .map(|(column, rect)| (*column, *rect))
+        .collect()
+}
+
+/// Draw the kanban columns
+fn draw_columns(f: &mut Frame, app: &App, area: Rect) {
+    for (column, rect) in column_areas(area) {
+        draw_column(f, app, column, rect);
     }
 }
 
@@ -87,37 +252,124 @@ fn draw_column(f: &mut Frame, app: &App, column: Column, area: Rect) {
     let tasks = app.tasks_for_column(&column);
     let is_selected_column = app.selected_column == column;
 
-    let title = match column {
-        Column::Todo => format!("TODO ({})", tasks.len()),
-        Column::InProgress => format!("IN PROGRESS ({})", tasks.len()),
-        Column::Blocked => format!("BLOCKED ({})", tasks.len()),
-        Column::InQa => format!("IN QA ({})", tasks.len()),
-        Column::Done => format!("DONE ({})", tasks.len()),
+    let name = match column {
+        Column::Todo => "TODO",
+        Column::InProgress => "IN PROGRESS",
+        Column::Blocked => "BLOCKED",
+        Column::InQa => "IN QA",
+        Column::Done => "DONE",
+    };
+    let title = match app.wip_of(column) {
+        Some((count, limit)) if count as i64 > limit => {
+            format!("{} ({}/{} ⚠)", name, count, limit)
+        }
+        Some((count, limit)) => format!("{} ({}/{})", name, count, limit),
+        None => format!("{} ({})", name, tasks.len()),
     };
 
-    let border_style = if is_selected_column {
-        Style::default().fg(Color::Yellow)
+    let is_drop_target = app.drag_task_id.is_some() && is_selected_column;
+    let border_style = if is_drop_target {
+        Style::default()
+            .fg(app.theme.selected_border)
+            .add_modifier(Modifier::BOLD)
+    } else if is_selected_column {
+        Style::default().fg(app.theme.selected_border)
+    } else {
+        Style::default().fg(app.theme.border)
+    };
+    let title_style = Style::default()
+        .fg(app.theme.columns.get(column.to_status()))
+        .add_modifier(Modifier::BOLD);
+
+    let block = Block::default()
+        .title(Span::styled(title, title_style))
+        .borders(Borders::ALL)
+        .border_style(border_style);
+
+    if app.swimlanes != Swimlanes::Off {
+        f.render_widget(block, area);
+        for cell in app.lane_cells(column) {
+            draw_lane(f, app, &tasks, &cell, is_selected_column);
+        }
+        return;
+    }
+
+    let offset = app.column_scroll[column as usize];
+    let visible = app.visible_cards(column);
+    let items = card_items(app, &tasks, offset, visible, is_selected_column);
+
+    let overflows = tasks.len() > visible;
+    let block = if overflows {
+        let last = (offset + visible).min(tasks.len());
+        block.title_bottom(format!(" {}-{}/{} ", offset + 1, last, tasks.len()))
     } else {
-        Style::default().fg(Color::DarkGray)
+        block
     };
 
-    let items: Vec<ListItem> = tasks
+    f.render_widget(List::new(items).block(block), area);
+
+    if overflows {
+        let positions = tasks.len().saturating_sub(visible) + 1;
+        let mut state = ScrollbarState::new(positions).position(offset);
+        f.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .style(border_style),
+            area.inner(Margin::new(0, 1)),
+            &mut state,
+        );
+    }
+}
+
+/// Draw one swimlane of a column: a label row above its cards
+fn draw_lane(f: &mut Frame, app: &App, tasks: &[&Task], cell: &LaneCell, is_selected_column: bool) {
+    let lane_tasks = &tasks[..cell.start + cell.len];
+    let visible = cell.visible();
+
+    let mut label = format!("{} ({})", cell.label, cell.len);
+    if cell.len > visible {
+        let last = (cell.offset + visible).min(cell.len);
+        label.push_str(&format!(" {}-{}", cell.offset + 1, last));
+    }
+    f.render_widget(
+        Paragraph::new(label).style(
+            Style::default()
+                .fg(app.theme.muted)
+                .add_modifier(Modifier::UNDERLINED),
+        ),
+        cell.header,
+    );
+
+    let items = card_items(app, lane_tasks, cell.start + cell.offset, visible, is_selected_column);
+    f.render_widget(List::new(items), cell.cards);
+}
+
+/// Render `count` cards of a column starting at index `first`
+fn card_items(
+    app: &App,
+    tasks: &[&Task],
+    first: usize,
+    count: usize,
+    is_selected_column: bool,
+) -> Vec<ListItem<'static>> {
+    tasks
         .iter()
         .enumerate()
+        .skip(first)
+        .take(count)
         .map(|(i, task)| {
             let is_selected = is_selected_column && i == app.selected_task_index;
-            widgets::task_card::render_task_item(task, is_selected)
+            widgets::task_card::render_task_item(
+                task,
+                is_selected,
+                app.blocker_count(&task.id),
+                app.labels_of(&task.id),
+                checklists::checklist_progress(app.checklist_of(&task.id)),
+                &app.theme,
+            )
         })
-        .collect();
-
-    let list = List::new(items).block(
-        Block::default()
-            .title(title)
-            .borders(Borders::ALL)
-            .border_style(border_style),
-    );
-
-    f.render_widget(list, area);
+        .collect()
 }
 
 /// Draw the metrics bar
@@ -128,19 +380,29 @@ fn draw_metrics(f: &mut Frame, app: &App, area: Rect) {
         .unwrap_or("No metrics available");
 
     let metrics = Paragraph::new(format!("METRICS: {}", metrics_text))
-        .style(Style::default().fg(Color::White))
-        .block(Block::default().borders(Borders::ALL));
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(app.theme.border)),
+        );
 
     f.render_widget(metrics, area);
 }
 
 /// Draw the status bar
 fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
+    if app.view_mode == ViewMode::Search {
+        let bar = Paragraph::new(format!("/{}_", app.search_query))
+            .style(Style::default().fg(app.theme.selected_border));
+        f.render_widget(bar, area);
+        return;
+    }
+
     let status = app.status_message.as_deref().unwrap_or(
-        "[j/k] Navigate  [h/l] Columns  [Enter] Details  [m] Move  [p] Progress  [d] Done  [r] Refresh",
+        "[hjkl] Navigate  [Enter] Details  [n] New  [a] Assign  [/] Search  [b] Blockers  [m] Move  [?] Help",
     );
 
-    let bar = Paragraph::new(status).style(Style::default().fg(Color::DarkGray));
+    let bar = Paragraph::new(status).style(Style::default().fg(app.theme.muted));
 
     f.render_widget(bar, area);
 }
@@ -163,7 +425,7 @@ fn draw_task_detail(f: &mut Frame, app: &App) {
                 Span::styled("Status: ", Style::default().add_modifier(Modifier::BOLD)),
                 Span::styled(
                     task.status.to_string(),
-                    Style::default().fg(status_color(&task.status)),
+                    Style::default().fg(app.theme.statuses.get(task.status)),
                 ),
             ]),
             Line::from(vec![
@@ -184,6 +446,21 @@ fn draw_task_detail(f: &mut Frame, app: &App) {
             ]),
         ];
 
+        let labels = app.labels_of(&task.id);
+        if !labels.is_empty() {
+            let mut spans = vec![Span::styled(
+                "Labels: ",
+                Style::default().add_modifier(Modifier::BOLD),
+            )];
+            for label in labels {
+                spans.push(Span::styled(
+                    format!("#{} ", label),
+                    Style::default().fg(app.theme.accent),
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+
         if let Some(desc) = &task.description {
             lines.push(Line::from(""));
             lines.push(Line::from(vec![Span::styled(
@@ -193,21 +470,192 @@ fn draw_task_detail(f: &mut Frame, app: &App) {
             lines.push(Line::from(desc.as_str()));
         }
 
+        let checklist = app.checklist_of(&task.id);
+        if !checklist.is_empty() {
+            let (done, total) = checklists::checklist_progress(checklist);
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled(
+                format!("Checklist ({}/{}):", done, total),
+                Style::default().add_modifier(Modifier::BOLD),
+            )]));
+            for item in checklist {
+                let (marker, style) = if item.done {
+                    ("[x]", Style::default().fg(app.theme.muted))
+                } else {
+                    ("[ ]", Style::default())
+                };
+                lines.push(Line::from(vec![Span::styled(
+                    format!("{} {}. {}", marker, item.position, item.text),
+                    style,
+                )]));
+            }
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![Span::styled(
+            format!("Comments ({}):", app.comments.len()),
+            Style::default().add_modifier(Modifier::BOLD),
+        )]));
+        for comment in &app.comments {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{} {}: ", comment.created_at.format("%Y-%m-%d %H:%M"), comment.author),
+                    Style::default().fg(app.theme.muted),
+                ),
+                Span::raw(comment.content.as_str()),
+            ]));
+        }
+
         let detail = Paragraph::new(lines)
             .wrap(Wrap { trim: true })
             .block(
                 Block::default()
-                    .title(" Task Details [Esc to close] ")
+                    .title(" Task Details [e] Edit [c] Comment [q/Esc] Close ")
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::Cyan)),
+                    .border_style(Style::default().fg(app.theme.accent)),
             );
 
         f.render_widget(detail, area);
     }
 }
 
+/// Draw the burndown chart view (remaining hours above, remaining tasks below)
+fn draw_burndown(f: &mut Frame, app: &App) {
+    let feature_name = app
+        .current_feature
+        .as_ref()
+        .map(|f| f.name.as_str())
+        .unwrap_or("No Feature");
+
+    let outer = Block::default()
+        .title(format!(" Burndown: {} [g/q/Esc] Close ", feature_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(app.theme.accent));
+    let inner = outer.inner(f.area());
+    f.render_widget(outer, f.area());
+
+    if app.burndown.is_empty() {
+        f.render_widget(Paragraph::new("No tasks in this feature yet"), inner);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(inner);
+
+    let hours: Vec<(f64, f64)> = app
+        .burndown
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (i as f64, p.remaining_hours))
+        .collect();
+    let tasks: Vec<(f64, f64)> = app
+        .burndown
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (i as f64, p.remaining_tasks as f64))
+        .collect();
+
+    let color = app.theme.statuses.in_progress;
+    draw_burndown_chart(f, app, chunks[0], "Remaining hours", &hours, color);
+    draw_burndown_chart(f, app, chunks[1], "Remaining tasks", &tasks, color);
+}
+
+/// Draw one burndown series as a line chart
+fn draw_burndown_chart(
+    f: &mut Frame,
+    app: &App,
+    area: Rect,
+    title: &str,
+    data: &[(f64, f64)],
+    color: Color,
+) {
+    let max_x = (data.len().saturating_sub(1)).max(1) as f64;
+    let max_y = data.iter().map(|(_, y)| *y).fold(0.0, f64::max).max(1.0);
+
+    let first = app.burndown.first().map(|p| p.date.format("%m-%d").to_string());
+    let last = app.burndown.last().map(|p| p.date.format("%m-%d").to_string());
+
+    let datasets = vec![Dataset::default()
+        .name(title)
+        .marker(symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(color))
+        .data(data)];
+
+    let chart = Chart::new(datasets)
+        .block(Block::default().title(format!(" {} ", title)))
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(app.theme.muted))
+                .bounds([0.0, max_x])
+                .labels(vec![
+                    Span::raw(first.unwrap_or_default()),
+                    Span::raw(last.unwrap_or_default()),
+                ]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(app.theme.muted))
+                .bounds([0.0, max_y])
+                .labels(vec![
+                    Span::raw("0"),
+                    Span::raw(format!("{:.0}", max_y / 2.0)),
+                    Span::raw(format!("{:.0}", max_y)),
+                ]),
+        );
+
+    f.render_widget(chart, area);
+}
+
+/// Draw the dependency graph view for the current feature
+fn draw_dependency_graph(f: &mut Frame, app: &App) {
+    let feature_name = app
+        .current_feature
+        .as_ref()
+        .map(|f| f.name.as_str())
+        .unwrap_or("No Feature");
+
+    let outer = Block::default()
+        .title(format!(" Dependencies: {} [j/k] Scroll [D/q/Esc] Close ", feature_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(app.theme.accent));
+    let inner = outer.inner(f.area());
+    f.render_widget(outer, f.area());
+
+    if app.tasks.is_empty() {
+        f.render_widget(Paragraph::new("No tasks in this feature yet"), inner);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner);
+
+    let rows = widgets::dep_graph::layout_graph(
+        &app.tasks,
+        &app.dependency_edges,
+        &app.external_dependencies,
+    );
+    let lines = widgets::dep_graph::render_graph_lines(&rows, &app.theme);
+    let max_scroll = (lines.len() as u16).saturating_sub(chunks[0].height);
+    f.render_widget(
+        Paragraph::new(lines).scroll((app.graph_scroll.min(max_scroll), 0)),
+        chunks[0],
+    );
+
+    let legend = Line::from(vec![
+        Span::styled("● ready  ", Style::default().fg(app.theme.statuses.done)),
+        Span::styled("○ waiting upstream  ", Style::default().fg(app.theme.statuses.blocked)),
+        Span::styled("✓ done", Style::default().fg(app.theme.muted)),
+    ]);
+    f.render_widget(Paragraph::new(legend), chunks[1]);
+}
+
 /// Draw help view
-fn draw_help(f: &mut Frame, _app: &App) {
+fn draw_help(f: &mut Frame, app: &App) {
     let area = centered_rect(50, 60, f.area());
 
     let help_text = vec![
@@ -215,7 +663,7 @@ fn draw_help(f: &mut Frame, _app: &App) {
             "Keyboard Shortcuts",
             Style::default()
                 .add_modifier(Modifier::BOLD)
-                .fg(Color::Cyan),
+                .fg(app.theme.accent),
         )),
         Line::from(""),
         Line::from("Navigation:"),
@@ -223,12 +671,27 @@ fn draw_help(f: &mut Frame, _app: &App) {
         Line::from("  k/↑     Move up in column"),
         Line::from("  h/←     Previous column"),
         Line::from("  l/→     Next column"),
+        Line::from("  PgUp/PgDn  Page through column"),
         Line::from("  Enter   View task details"),
+        Line::from("  /       Search (words, @agent, #label, p<N)"),
+        Line::from("  A       Only my tasks (agent from config)"),
+        Line::from("  Esc     Clear filter"),
+        Line::from("  s       Swimlanes (agent/priority/off)"),
+        Line::from("  Mouse   Click to select, drag to move"),
         Line::from(""),
         Line::from("Actions:"),
+        Line::from("  n       Create a new task"),
+        Line::from("  e       Edit task (in details)"),
+        Line::from("  c       Add comment (in details)"),
+        Line::from("  a       Assign to an agent"),
+        Line::from("  b       Blockers panel (resolve/escalate)"),
+        Line::from("  B       Add a blocker to the task"),
+        Line::from("  g       Burndown chart"),
+        Line::from("  D       Dependency graph"),
         Line::from("  m       Move to next valid state"),
         Line::from("  p       Move to in-progress"),
         Line::from("  d       Mark as done (if in QA)"),
+        Line::from("  u       Undo last move or assignment"),
         Line::from("  r       Refresh data"),
         Line::from(""),
         Line::from("General:"),
@@ -238,9 +701,9 @@ fn draw_help(f: &mut Frame, _app: &App) {
 
     let help = Paragraph::new(help_text).block(
         Block::default()
-            .title(" Help [?/Esc to close] ")
+            .title(" Help [?/q/Esc to close] ")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan)),
+            .border_style(Style::default().fg(app.theme.accent)),
     );
 
     f.render_widget(help, area);
@@ -266,14 +729,3 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         ])
         .split(popup_layout[1])[1]
 }
-
-/// Get color for status
-fn status_color(status: &crate::state_machine::TaskStatus) -> Color {
-    match status {
-        crate::state_machine::TaskStatus::Todo => Color::White,
-        crate::state_machine::TaskStatus::InProgress => Color::Yellow,
-        crate::state_machine::TaskStatus::Blocked => Color::Red,
-        crate::state_machine::TaskStatus::InQa => Color::Cyan,
-        crate::state_machine::TaskStatus::Done => Color::Green,
-    }
-}
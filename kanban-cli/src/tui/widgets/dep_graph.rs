@@ -0,0 +1,301 @@
+//! Dependency graph widget: lays out a feature's task DAG as an ASCII tree
+
+use std::collections::{HashMap, HashSet};
+
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::models::Task;
+use crate::state_machine::TaskStatus;
+use crate::tui::theme::Theme;
+
+/// Where a task stands relative to its dependencies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyState {
+    /// The task itself is done
+    Done,
+    /// Every dependency is done, so the task can be worked on
+    Ready,
+    /// Some dependencies are not done yet; `external` of them belong to
+    /// other features
+    Waiting { pending: usize, external: usize },
+}
+
+/// One row of the rendered tree
+#[derive(Debug, Clone)]
+pub struct GraphRow<'a> {
+    /// Tree connector drawn before the task (e.g. "│   └── ")
+    pub prefix: String,
+    pub task: &'a Task,
+    pub state: DependencyState,
+    /// The task was already expanded under another dependency
+    pub repeated: bool,
+}
+
+/// Lay out tasks as a tree rooted at tasks without dependencies
+///
+/// `edges` are (task_id, depends_on_task_id) pairs. A task appears under
+/// each task it depends on, but its own dependents are only expanded the
+/// first time it is shown. `external` lists dependencies on tasks in other
+/// features; they are not drawn but still count toward a task's state.
+pub fn layout_graph<'a>(
+    tasks: &'a [Task],
+    edges: &[(String, String)],
+    external: &[(String, Task)],
+) -> Vec<GraphRow<'a>> {
+    let by_id: HashMap<&str, &Task> = tasks.iter().map(|t| (t.id.as_str(), t)).collect();
+
+    let mut dependencies: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (task_id, depends_on) in edges {
+        // Ignore edges to tasks outside this set
+        if let (Some(task), Some(dep)) =
+            (by_id.get(task_id.as_str()), by_id.get(depends_on.as_str()))
+        {
+            dependencies.entry(&task.id).or_default().push(&dep.id);
+            dependents.entry(&dep.id).or_default().push(&task.id);
+        }
+    }
+
+    // Keep children in board order (priority, then creation)
+    let order: HashMap<&str, usize> = tasks
+        .iter()
+        .enumerate()
+        .map(|(i, t)| (t.id.as_str(), i))
+        .collect();
+    for children in dependents.values_mut() {
+        children.sort_by_key(|id| order[id]);
+    }
+
+    let state_of = |task: &Task| {
+        if task.status == TaskStatus::Done {
+            return DependencyState::Done;
+        }
+        let internal = dependencies
+            .get(task.id.as_str())
+            .map(|deps| {
+                deps.iter()
+                    .filter(|id| by_id[*id].status != TaskStatus::Done)
+                    .count()
+            })
+            .unwrap_or(0);
+        let external = external
+            .iter()
+            .filter(|(id, dep)| *id == task.id && dep.status != TaskStatus::Done)
+            .count();
+        if internal + external == 0 {
+            DependencyState::Ready
+        } else {
+            DependencyState::Waiting {
+                pending: internal + external,
+                external,
+            }
+        }
+    };
+
+    let mut rows = Vec::new();
+    let mut expanded = HashSet::new();
+    let mut visit = Visit {
+        by_id: &by_id,
+        dependents: &dependents,
+        state_of: &state_of,
+        expanded: &mut expanded,
+        rows: &mut rows,
+    };
+
+    for task in tasks {
+        if !dependencies.contains_key(task.id.as_str()) {
+            visit.walk(task, String::new(), String::new());
+        }
+    }
+    // Anything left over is only reachable through a cycle
+    for task in tasks {
+        if !visit.expanded.contains(task.id.as_str()) {
+            visit.walk(task, String::new(), String::new());
+        }
+    }
+
+    rows
+}
+
+/// Depth-first walk state for `layout_graph`
+struct Visit<'a, 'b, F> {
+    by_id: &'b HashMap<&'a str, &'a Task>,
+    dependents: &'b HashMap<&'a str, Vec<&'a str>>,
+    state_of: &'b F,
+    expanded: &'b mut HashSet<&'a str>,
+    rows: &'b mut Vec<GraphRow<'a>>,
+}
+
+impl<'a, F: Fn(&Task) -> DependencyState> Visit<'a, '_, F> {
+    fn walk(&mut self, task: &'a Task, prefix: String, child_indent: String) {
+        let repeated = !self.expanded.insert(task.id.as_str());
+        self.rows.push(GraphRow {
+            prefix,
+            task,
+            state: (self.state_of)(task),
+            repeated,
+        });
+        if repeated {
+            return;
+        }
+
+        let children = self
+            .dependents
+            .get(task.id.as_str())
+            .cloned()
+            .unwrap_or_default();
+        for (i, child) in children.iter().enumerate() {
+            let last = i + 1 == children.len();
+            let (branch, indent) = if last {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            self.walk(
+                self.by_id[child],
+                format!("{}{}", child_indent, branch),
+                format!("{}{}", child_indent, indent),
+            );
+        }
+    }
+}
+
+/// Render graph rows as styled lines
+pub fn render_graph_lines(rows: &[GraphRow], theme: &Theme) -> Vec<Line<'static>> {
+    rows.iter()
+        .map(|row| {
+            let (marker, state_style, note) = match row.state {
+                DependencyState::Done => ("✓", Style::default().fg(theme.muted), String::new()),
+                DependencyState::Ready => (
+                    "●",
+                    Style::default()
+                        .fg(theme.statuses.done)
+                        .add_modifier(Modifier::BOLD),
+                    " ready".to_string(),
+                ),
+                DependencyState::Waiting { pending, external } => (
+                    "○",
+                    Style::default().fg(theme.statuses.blocked),
+                    match external {
+                        0 => format!(" waiting on {}", pending),
+                        n => format!(" waiting on {} ({} in other features)", pending, n),
+                    },
+                ),
+            };
+
+            let mut spans = vec![
+                Span::styled(row.prefix.clone(), Style::default().fg(theme.muted)),
+                Span::styled(format!("{} ", marker), state_style),
+                Span::styled(row.task.id.clone(), Style::default().fg(theme.accent)),
+                Span::raw(" "),
+                Span::styled(row.task.title.clone(), state_style),
+            ];
+            if row.repeated {
+                spans.push(Span::styled(
+                    " (see above)",
+                    Style::default().fg(theme.muted),
+                ));
+            } else {
+                spans.push(Span::styled(
+                    format!("  [{}]{}", row.task.status, note),
+                    Style::default().fg(theme.statuses.get(row.task.status)),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: &str, status: TaskStatus) -> Task {
+        let mut task = Task::new(id.to_string(), "f".to_string(), id.to_string());
+        task.status = status;
+        task
+    }
+
+    fn edge(task_id: &str, depends_on: &str) -> (String, String) {
+        (task_id.to_string(), depends_on.to_string())
+    }
+
+    #[test]
+    fn test_layout_graph() {
+        let tasks = vec![
+            task("A", TaskStatus::Done),
+            task("B", TaskStatus::Todo),
+            task("C", TaskStatus::Todo),
+            task("D", TaskStatus::Todo),
+        ];
+        // B and C depend on A; D depends on both B and C
+        let edges = vec![
+            edge("B", "A"),
+            edge("C", "A"),
+            edge("D", "B"),
+            edge("D", "C"),
+        ];
+
+        let rows = layout_graph(&tasks, &edges, &[]);
+        let summary: Vec<(&str, &str, bool)> = rows
+            .iter()
+            .map(|r| (r.prefix.as_str(), r.task.id.as_str(), r.repeated))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("", "A", false),
+                ("├── ", "B", false),
+                ("│   └── ", "D", false),
+                ("└── ", "C", false),
+                ("    └── ", "D", true),
+            ]
+        );
+
+        assert_eq!(rows[0].state, DependencyState::Done);
+        assert_eq!(rows[1].state, DependencyState::Ready);
+        assert_eq!(
+            rows[2].state,
+            DependencyState::Waiting {
+                pending: 2,
+                external: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_layout_graph_external_dependencies() {
+        let tasks = vec![task("A", TaskStatus::Todo), task("B", TaskStatus::Todo)];
+        let mut upstream = task("X", TaskStatus::InProgress);
+        upstream.feature_id = "other".to_string();
+        let mut finished = task("Y", TaskStatus::Done);
+        finished.feature_id = "other".to_string();
+        let external = vec![("A".to_string(), upstream), ("B".to_string(), finished)];
+
+        let rows = layout_graph(&tasks, &[], &external);
+        assert_eq!(
+            rows[0].state,
+            DependencyState::Waiting {
+                pending: 1,
+                external: 1
+            }
+        );
+        // A finished upstream task in another feature does not hold the task up
+        assert_eq!(rows[1].state, DependencyState::Ready);
+    }
+
+    #[test]
+    fn test_layout_graph_independent_tasks() {
+        let tasks = vec![
+            task("A", TaskStatus::Todo),
+            task("B", TaskStatus::InProgress),
+        ];
+        let rows = layout_graph(&tasks, &[edge("A", "missing")], &[]);
+        assert_eq!(rows.len(), 2);
+        assert!(rows.iter().all(|r| r.prefix.is_empty()));
+        assert!(rows.iter().all(|r| r.state == DependencyState::Ready));
+    }
+}
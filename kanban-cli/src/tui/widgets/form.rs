@@ -0,0 +1,187 @@
+//! Text input form widget for popups
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::tui::theme::Theme;
+
+/// A single labelled text field
+#[derive(Debug, Clone)]
+pub struct FormField {
+    pub label: &'static str,
+    pub value: String,
+}
+
+/// Outcome of a key press inside a form
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormAction {
+    /// Keep editing
+    None,
+    /// User confirmed the form
+    Submit,
+    /// User dismissed the form
+    Cancel,
+}
+
+/// A popup form made of text fields
+#[derive(Debug, Clone)]
+pub struct Form {
+    pub title: String,
+    pub fields: Vec<FormField>,
+    pub focused: usize,
+}
+
+impl Form {
+    /// Create a form from (label, initial value) pairs
+    pub fn new(title: impl Into<String>, fields: Vec<(&'static str, String)>) -> Self {
+        Self {
+            title: title.into(),
+            fields: fields
+                .into_iter()
+                .map(|(label, value)| FormField { label, value })
+                .collect(),
+            focused: 0,
+        }
+    }
+
+    /// Get the trimmed value of a field by label
+    pub fn value(&self, label: &str) -> &str {
+        self.fields
+            .iter()
+            .find(|f| f.label == label)
+            .map(|f| f.value.trim())
+            .unwrap_or("")
+    }
+
+    /// Get the value of a field, or None if it is blank
+    pub fn optional(&self, label: &str) -> Option<String> {
+        let value = self.value(label);
+        if value.is_empty() {
+            None
+        } else {
+            Some(value.to_string())
+        }
+    }
+
+    /// Move focus to the next field
+    pub fn focus_next(&mut self) {
+        if !self.fields.is_empty() {
+            self.focused = (self.focused + 1) % self.fields.len();
+        }
+    }
+
+    /// Move focus to the previous field
+    pub fn focus_prev(&mut self) {
+        if !self.fields.is_empty() {
+            self.focused = (self.focused + self.fields.len() - 1) % self.fields.len();
+        }
+    }
+
+    /// Handle a key press, editing the focused field
+    pub fn handle_key(&mut self, key: KeyEvent) -> FormAction {
+        match key.code {
+            KeyCode::Esc => return FormAction::Cancel,
+            KeyCode::Enter => return FormAction::Submit,
+            KeyCode::Tab | KeyCode::Down => self.focus_next(),
+            KeyCode::BackTab | KeyCode::Up => self.focus_prev(),
+            KeyCode::Backspace => {
+                if let Some(field) = self.fields.get_mut(self.focused) {
+                    field.value.pop();
+                }
+            }
+            KeyCode::Char(c) => {
+                if let Some(field) = self.fields.get_mut(self.focused) {
+                    field.value.push(c);
+                }
+            }
+            _ => {}
+        }
+        FormAction::None
+    }
+}
+
+/// Render a form as a popup over the given area
+pub fn render_form(f: &mut Frame, form: &Form, area: Rect, theme: &Theme) {
+    let label_width = form.fields.iter().map(|f| f.label.len()).max().unwrap_or(0);
+
+    let mut lines = Vec::new();
+    for (i, field) in form.fields.iter().enumerate() {
+        let is_focused = i == form.focused;
+        let label_style = if is_focused {
+            Style::default()
+                .fg(theme.selected_border)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().add_modifier(Modifier::BOLD)
+        };
+        let cursor = if is_focused { "_" } else { "" };
+
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:<width$}: ", field.label, width = label_width),
+                label_style,
+            ),
+            Span::raw(format!("{}{}", field.value, cursor)),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "[Tab] Next field  [Enter] Save  [Esc] Cancel",
+        Style::default().fg(theme.muted),
+    )));
+
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(" {} ", form.title))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_form_editing() {
+        let mut form = Form::new(
+            "Test",
+            vec![("Title", String::new()), ("Priority", "100".to_string())],
+        );
+
+        form.handle_key(key(KeyCode::Char('a')));
+        form.handle_key(key(KeyCode::Char('b')));
+        form.handle_key(key(KeyCode::Backspace));
+        assert_eq!(form.value("Title"), "a");
+
+        form.handle_key(key(KeyCode::Tab));
+        form.handle_key(key(KeyCode::Char('5')));
+        assert_eq!(form.value("Priority"), "1005");
+        assert_eq!(form.optional("Missing"), None);
+    }
+
+    #[test]
+    fn test_form_focus_wraps() {
+        let mut form = Form::new("Test", vec![("A", String::new()), ("B", String::new())]);
+        form.focus_prev();
+        assert_eq!(form.focused, 1);
+        form.focus_next();
+        assert_eq!(form.focused, 0);
+        assert_eq!(form.handle_key(key(KeyCode::Enter)), FormAction::Submit);
+        assert_eq!(form.handle_key(key(KeyCode::Esc)), FormAction::Cancel);
+    }
+}
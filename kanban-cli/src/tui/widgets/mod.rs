@@ -1,3 +1,5 @@
 //! Custom widgets for the TUI
 
+pub mod dep_graph;
+pub mod form;
 pub mod task_card;
@@ -1,20 +1,31 @@
 //! Task card widget for the kanban board
 
 use ratatui::{
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::ListItem,
 };
 
 use crate::models::Task;
-use crate::state_machine::TaskStatus;
+use crate::tui::theme::Theme;
+
+/// Height of a rendered card in terminal rows (including the separator)
+pub const CARD_HEIGHT: u16 = 5;
 
 /// Render a task as a list item for the column
-pub fn render_task_item(task: &Task, is_selected: bool) -> ListItem<'static> {
-    let mut style = Style::default();
-    if is_selected {
-        style = style.bg(Color::DarkGray).add_modifier(Modifier::BOLD);
-    }
+pub fn render_task_item(
+    task: &Task,
+    is_selected: bool,
+    blocker_count: usize,
+    labels: &[String],
+    checklist: (usize, usize),
+    theme: &Theme,
+) -> ListItem<'static> {
+    let style = if is_selected {
+        theme.selected
+    } else {
+        Style::default()
+    };
 
     // Truncate title if needed
     let max_title_len = 18;
@@ -25,37 +36,63 @@ pub fn render_task_item(task: &Task, is_selected: bool) -> ListItem<'static> {
     };
 
     // Build the display lines
-    let id_line = Line::from(vec![Span::styled(
+    let mut id_spans = vec![Span::styled(
         task.id.clone(),
         Style::default()
-            .fg(Color::Cyan)
+            .fg(theme.accent)
             .add_modifier(Modifier::BOLD),
-    )]);
+    )];
+    if blocker_count > 0 {
+        id_spans.push(Span::styled(
+            format!(" 🚫{}", blocker_count),
+            Style::default().fg(theme.statuses.blocked),
+        ));
+    }
+    let (checked, total) = checklist;
+    if total > 0 {
+        let color = if checked == total {
+            theme.statuses.done
+        } else {
+            theme.muted
+        };
+        id_spans.push(Span::styled(
+            format!(" ☑{}/{}", checked, total),
+            Style::default().fg(color),
+        ));
+    }
+    let id_line = Line::from(id_spans);
 
     let title_line = Line::from(vec![Span::raw(title)]);
 
-    // Info line: priority, estimate, agent
+    // Info line: priority, estimate, label chips
     let mut info_parts = vec![format!("P:{}", task.priority)];
 
     if let Some(est) = task.estimated_hours {
         info_parts.push(format!("E:{:.0}h", est));
     }
 
-    let info_line = Line::from(vec![Span::styled(
+    let mut info_spans = vec![Span::styled(
         info_parts.join(" "),
-        Style::default().fg(Color::DarkGray),
-    )]);
+        Style::default().fg(theme.muted),
+    )];
+    for label in labels {
+        info_spans.push(Span::styled(
+            format!(" #{}", label),
+            Style::default().fg(theme.accent),
+        ));
+    }
+    let info_line = Line::from(info_spans);
 
     // Agent line if assigned
     let agent_line = if let Some(agent) = &task.assigned_agent {
         Line::from(vec![Span::styled(
             truncate(agent, 15),
-            Style::default().fg(agent_color(&task.status)),
+            Style::default().fg(theme.statuses.get(task.status)),
         )])
     } else {
         Line::from(vec![Span::styled(
             "Unassigned",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )])
     };
 
@@ -71,17 +108,6 @@ pub fn render_task_item(task: &Task, is_selected: bool) -> ListItem<'static> {
     ListItem::new(lines).style(style)
 }
 
-/// Get color based on task status
-fn agent_color(status: &TaskStatus) -> Color {
-    match status {
-        TaskStatus::Todo => Color::Gray,
-        TaskStatus::InProgress => Color::Yellow,
-        TaskStatus::Blocked => Color::Red,
-        TaskStatus::InQa => Color::Cyan,
-        TaskStatus::Done => Color::Green,
-    }
-}
-
 /// Truncate a string to max length
 fn truncate(s: &str, max_len: usize) -> String {
     if s.len() > max_len {